@@ -0,0 +1,45 @@
+//! compares the copying conversions between 4KB byte arrays and recursive arrays ([`RecursiveArray::from_array_copy`]
+//! and [`RecursiveArray::to_array_copy`]) against the transmuting ones ([`RecursiveArray::from_array`] and
+//! [`RecursiveArray::to_array`]).
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use recursive_array::{RecursiveArray, RecursiveArrayArrayWrapper, RecursiveArrayConcatenation};
+use std::hint::black_box;
+
+const SIZE: usize = 4096;
+
+/// a recursive array of `SIZE` bytes which is made of more than a single wrapper.
+type Bytes = RecursiveArrayConcatenation<
+    u8,
+    RecursiveArrayArrayWrapper<{ SIZE / 2 }, u8>,
+    RecursiveArrayArrayWrapper<{ SIZE / 2 }, u8>,
+>;
+
+fn bench_from_array(c: &mut Criterion) {
+    let array: [u8; SIZE] = core::array::from_fn(|i| i as u8);
+    let mut group = c.benchmark_group("from_array_4kb");
+    group.bench_function("from_array", |bencher| {
+        bencher.iter(|| Bytes::from_array(black_box(array)))
+    });
+    group.bench_function("from_array_copy", |bencher| {
+        bencher.iter(|| Bytes::from_array_copy(black_box(&array)))
+    });
+    group.finish();
+}
+
+fn bench_to_array(c: &mut Criterion) {
+    let array: [u8; SIZE] = core::array::from_fn(|i| i as u8);
+    let bytes = Bytes::from_array(array);
+    let mut group = c.benchmark_group("to_array_4kb");
+    // `to_array` consumes the array, so the equivalent of `to_array_copy` is converting a clone of it.
+    group.bench_function("clone_to_array", |bencher| {
+        bencher.iter(|| black_box(&bytes).clone().to_array::<SIZE>())
+    });
+    group.bench_function("to_array_copy", |bencher| {
+        bencher.iter(|| black_box(&bytes).to_array_copy::<SIZE>())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_from_array, bench_to_array);
+criterion_main!(benches);
@@ -0,0 +1,45 @@
+//! compares [`RecursiveByteArray::fast_cmp`] against the `Ord` implementation of the wrapper and against an element by
+//! element comparison of 4KB byte arrays.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use recursive_array::{RecursiveArray, RecursiveArrayArrayWrapper, RecursiveByteArray};
+use std::hint::black_box;
+
+const SIZE: usize = 4096;
+
+/// returns 2 arrays which only differ in their last byte, so that comparing them has to go over all of their bytes.
+fn arrays() -> (
+    RecursiveArrayArrayWrapper<SIZE, u8>,
+    RecursiveArrayArrayWrapper<SIZE, u8>,
+) {
+    let a = RecursiveArrayArrayWrapper::new(core::array::from_fn(|i| i as u8));
+    let mut b = a.clone();
+    b.as_mut_slice()[SIZE - 1] ^= 1;
+    (a, b)
+}
+
+fn bench_fast_cmp(c: &mut Criterion) {
+    let (a, b) = arrays();
+    let mut group = c.benchmark_group("cmp_4kb");
+    group.bench_function("fast_cmp", |bencher| {
+        bencher.iter(|| black_box(&a).fast_cmp(black_box(&b)))
+    });
+    group.bench_function("ord", |bencher| {
+        bencher.iter(|| black_box(&a).cmp(black_box(&b)))
+    });
+    group.bench_function("element_by_element", |bencher| {
+        bencher.iter(|| {
+            black_box(&a)
+                .as_slice()
+                .iter()
+                .zip(black_box(&b).as_slice())
+                .map(|(x, y)| x.cmp(y))
+                .find(|ordering| ordering.is_ne())
+                .unwrap_or(core::cmp::Ordering::Equal)
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_fast_cmp);
+criterion_main!(benches);
@@ -1,9 +1,50 @@
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "rayon")]
+extern crate std;
+// lets the tests use the exported macros, which refer to this crate by its name.
+#[cfg(test)]
+extern crate self as recursive_array;
+
 use core::marker::PhantomData;
+use core::ops::{
+    Add, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, Mul, Not, Sub,
+};
+
+/// defines trait methods which combine the elements of two recursive arrays of integers element-wise using the given
+/// methods of the [`Integer`] trait.
+macro_rules! integer_elementwise_methods {
+    ($($method: ident => $description: literal),+ $(,)?) => {
+        $(
+            #[doc = concat!("returns an array of the element-wise ", $description, " of this array and the given array.")]
+            ///
+            /// # Panics
+            ///
+            /// this function panics if the length of the other array or the length of the output array (`N`) is not
+            /// equal to `Self::LENGTH`. this condition currently can't be checked at compile time due to the limitation
+            /// of const generics.
+            fn $method<const N: usize>(
+                &self,
+                other: &impl RecursiveArray<T>,
+            ) -> RecursiveArrayArrayWrapper<N, T>
+            where
+                T: Integer,
+            {
+                zip_elements(self.as_slice(), other.as_slice(), T::$method)
+            }
+        )+
+    };
+}
 
 /// a trait which when implemented by some type states that the type's memory representation can be treated directly as a slice of
 /// type `T`, with a length that is according to the `LENGTH` constant.
+///
+/// # Safety
+///
+/// implementors must guarantee that their memory representation is exactly `LENGTH` consecutive elements of type `T`, with no
+/// padding or other data in between them.
 pub unsafe trait RecursiveArray<T>: Sized + AsRef<[T]> + AsMut<[T]> {
     /// the length of this array
     const LENGTH: usize;
@@ -21,6 +62,11 @@ pub unsafe trait RecursiveArray<T>: Sized + AsRef<[T]> + AsMut<[T]> {
         Self::LENGTH
     }
 
+    /// returns whether this recursive array is empty.
+    fn is_empty(&self) -> bool {
+        Self::LENGTH == 0
+    }
+
     /// converts the given array to a recursive array.
     ///
     /// # Panics
@@ -38,8 +84,61 @@ pub unsafe trait RecursiveArray<T>: Sized + AsRef<[T]> + AsMut<[T]> {
         unsafe { runtime_checked_transmute(array) }
     }
 
+    /// copies the elements of the given array into a new recursive array.
+    ///
+    /// unlike [`from_array`](RecursiveArray::from_array), this copies the elements directly into the new recursive array
+    /// using [`copy_nonoverlapping`](core::ptr::copy_nonoverlapping), instead of transmuting the array.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if the length of the array (`N`) is not equal to `Self::LENGTH`.
+    /// this condition currently can't be checked at compile time due to the limitation of const generics.
+    fn from_array_copy<const N: usize>(array: &[T; N]) -> Self
+    where
+        T: Copy,
+    {
+        if N != Self::LENGTH {
+            panic!(
+                "tried to convert an array of length {} to a recursive array of length {}",
+                N,
+                Self::LENGTH,
+            );
+        }
+        let mut result = core::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            // empty arrays may be zero sized types which are not aligned for `T`, so they can't be copied into.
+            if N != 0 {
+                core::ptr::copy_nonoverlapping(array.as_ptr(), result.as_mut_ptr().cast::<T>(), N);
+            }
+            result.assume_init()
+        }
+    }
+
     /// converts this recrusive array to a regular array (`[T; N]`).
     ///
+    /// when the type of this array is a concrete type `R`, `N` doesn't have to be computed by hand, since it can be computed
+    /// using the [`length`] function, like `array.to_array::<{ length::<T, R>() }>()`. inferring it from the structure of
+    /// the array without naming its type requires `generic_const_exprs`, which is not available on stable rust.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use recursive_array::{length, RecursiveArray, RecursiveArrayArrayWrapper, RecursiveArrayConcatenation};
+    ///
+    /// type Combined = RecursiveArrayConcatenation<
+    ///     i32,
+    ///     RecursiveArrayArrayWrapper<2, i32>,
+    ///     RecursiveArrayArrayWrapper<3, i32>,
+    /// >;
+    ///
+    /// let combined: Combined = RecursiveArrayConcatenation::new(
+    ///     RecursiveArrayArrayWrapper::new([1, 2]),
+    ///     RecursiveArrayArrayWrapper::new([3, 4, 5]),
+    /// );
+    /// let array = combined.to_array::<{ length::<i32, Combined>() }>();
+    /// assert_eq!(array, [1, 2, 3, 4, 5]);
+    /// ```
+    ///
     /// # Panics
     ///
     /// this function panics if the length of the array (`N`) is not equal to `Self::LENGTH`.
@@ -55,6 +154,28 @@ pub unsafe trait RecursiveArray<T>: Sized + AsRef<[T]> + AsMut<[T]> {
         unsafe { runtime_checked_transmute(self) }
     }
 
+    /// copies the elements of this recursive array into a regular array (`[T; N]`).
+    ///
+    /// unlike [`to_array`](RecursiveArray::to_array), this doesn't consume this array, and doesn't rely on transmuting it.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if the length of the array (`N`) is not equal to `Self::LENGTH`.
+    /// this condition currently can't be checked at compile time due to the limitation of const generics.
+    fn to_array_copy<const N: usize>(&self) -> [T; N]
+    where
+        T: Copy,
+    {
+        match self.as_slice().first_chunk() {
+            Some(array) if N == Self::LENGTH => *array,
+            _ => panic!(
+                "tried to convert a recursive array of length {} to an array of length {}",
+                Self::LENGTH,
+                N,
+            ),
+        }
+    }
+
     /// converts the given slice to a recursive array reference. this is a zero cost operation, which just casts the slice.
     ///
     /// # Panics
@@ -88,13 +209,170 @@ pub unsafe trait RecursiveArray<T>: Sized + AsRef<[T]> + AsMut<[T]> {
     }
 
     /// returns the elements of this array as a slice.
+    ///
+    /// the base pointer of the returned slice is the address of `self`, which is aligned to `align_of::<T>()`. it is not
+    /// guaranteed to have any greater alignment, so re-casting it to a type with a stricter alignment requirement is unsound.
     fn as_slice(&self) -> &[T] {
-        unsafe { core::slice::from_raw_parts(self as *const Self as *const T, Self::LENGTH) }
+        if Self::LENGTH == 0 {
+            // empty arrays may be zero sized types which are not aligned for `T`.
+            return &[];
+        }
+        let ptr = self as *const Self as *const T;
+        debug_assert!(
+            ptr.is_aligned(),
+            "recursive array is not properly aligned for its element type"
+        );
+        unsafe { core::slice::from_raw_parts(ptr, Self::LENGTH) }
     }
 
     /// returns the elements of this array as a mutable slice.
+    ///
+    /// the base pointer of the returned slice is the address of `self`, which is aligned to `align_of::<T>()`. it is not
+    /// guaranteed to have any greater alignment, so re-casting it to a type with a stricter alignment requirement is unsound.
     fn as_mut_slice(&mut self) -> &mut [T] {
-        unsafe { core::slice::from_raw_parts_mut(self as *mut Self as *mut T, Self::LENGTH) }
+        if Self::LENGTH == 0 {
+            // empty arrays may be zero sized types which are not aligned for `T`.
+            return &mut [];
+        }
+        let ptr = self as *mut Self as *mut T;
+        debug_assert!(
+            ptr.is_aligned(),
+            "recursive array is not properly aligned for its element type"
+        );
+        unsafe { core::slice::from_raw_parts_mut(ptr, Self::LENGTH) }
+    }
+
+    /// returns a reference to the elements of this array as a regular array (`[T; N]`).
+    ///
+    /// # Panics
+    ///
+    /// this function panics if the length of the array (`N`) is not equal to `Self::LENGTH`.
+    /// this condition currently can't be checked at compile time due to the limitation of const generics.
+    fn as_array_ref<const N: usize>(&self) -> &[T; N] {
+        match self.try_as_array_ref() {
+            Some(array) => array,
+            None => panic!(
+                "tried to convert a recursive array of length {} to an array of length {}",
+                Self::LENGTH,
+                N,
+            ),
+        }
+    }
+
+    /// returns a mutable reference to the elements of this array as a regular array (`[T; N]`).
+    ///
+    /// # Panics
+    ///
+    /// this function panics if the length of the array (`N`) is not equal to `Self::LENGTH`.
+    /// this condition currently can't be checked at compile time due to the limitation of const generics.
+    fn as_array_mut<const N: usize>(&mut self) -> &mut [T; N] {
+        match self.try_as_array_mut() {
+            Some(array) => array,
+            None => panic!(
+                "tried to convert a recursive array of length {} to an array of length {}",
+                Self::LENGTH,
+                N,
+            ),
+        }
+    }
+
+    /// returns a reference to the elements of this array as a regular array (`[T; N]`), or `None` if the length of the array
+    /// (`N`) is not equal to `Self::LENGTH`.
+    fn try_as_array_ref<const N: usize>(&self) -> Option<&[T; N]> {
+        self.as_slice().try_into().ok()
+    }
+
+    /// returns a mutable reference to the elements of this array as a regular array (`[T; N]`), or `None` if the length of
+    /// the array (`N`) is not equal to `Self::LENGTH`.
+    fn try_as_array_mut<const N: usize>(&mut self) -> Option<&mut [T; N]> {
+        self.as_mut_slice().try_into().ok()
+    }
+
+    /// divides this array into two mutable slices at the given index.
+    /// the first slice contains the elements in `[0, mid)` and the second contains the elements in `[mid, LENGTH)`.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if `mid` is greater than `Self::LENGTH`.
+    fn split_at_mut(&mut self, mid: usize) -> (&mut [T], &mut [T]) {
+        if mid > Self::LENGTH {
+            panic!(
+                "tried to split a recursive array of length {} at index {}",
+                Self::LENGTH,
+                mid,
+            );
+        }
+        self.as_mut_slice().split_at_mut(mid)
+    }
+
+    /// swaps all elements of this array with the elements of the given slice.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if the length of the slice is not equal to `Self::LENGTH`.
+    fn swap_with_slice(&mut self, other: &mut [T]) {
+        if other.len() != Self::LENGTH {
+            panic!(
+                "tried to swap a recursive array of length {} with a slice of length {}",
+                Self::LENGTH,
+                other.len(),
+            );
+        }
+        self.as_mut_slice().swap_with_slice(other)
+    }
+
+    /// swaps all elements of this array with the elements of the given recursive array.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if the length of the other array (`R::LENGTH`) is not equal to `Self::LENGTH`.
+    /// this condition currently can't be checked at compile time due to the limitation of const generics.
+    fn swap_with<R: RecursiveArray<T>>(&mut self, other: &mut R) {
+        if R::LENGTH != Self::LENGTH {
+            panic!(
+                "tried to swap a recursive array of length {} with a recursive array of length {}",
+                Self::LENGTH,
+                R::LENGTH,
+            );
+        }
+        self.as_mut_slice().swap_with_slice(other.as_mut_slice())
+    }
+
+    /// returns an array of the elements of this array rotated to the left by `K` positions, such that the element at index
+    /// `K % Self::LENGTH` becomes the first one. since `K` is a constant, the rotation can be fully optimized at compile
+    /// time.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if the length of the output array (`N`) is not equal to `Self::LENGTH`.
+    /// this condition currently can't be checked at compile time due to the limitation of const generics.
+    fn rotate_left_const<const K: usize, const N: usize>(self) -> RecursiveArrayArrayWrapper<N, T>
+    where
+        T: Copy,
+    {
+        check_output_length::<N>(Self::LENGTH);
+        let slice = self.as_slice();
+        RecursiveArrayArrayWrapper::new(core::array::from_fn(|i| slice[(i + K % N) % N]))
+    }
+
+    /// shifts all elements of this array one position towards its end, dropping the last element and placing the given
+    /// value at the front, like a shift register. if this array is empty, the given value is dropped.
+    fn shift_right(&mut self, fill: T) {
+        let slice = self.as_mut_slice();
+        if !slice.is_empty() {
+            slice.rotate_right(1);
+            slice[0] = fill;
+        }
+    }
+
+    /// shifts all elements of this array one position towards its front, dropping the first element and placing the given
+    /// value at the end, like a shift register. if this array is empty, the given value is dropped.
+    fn shift_left(&mut self, fill: T) {
+        let slice = self.as_mut_slice();
+        if let Some(last_index) = slice.len().checked_sub(1) {
+            slice.rotate_left(1);
+            slice[last_index] = fill;
+        }
     }
 
     /// appends an element to the back of this array.
@@ -106,6 +384,27 @@ pub unsafe trait RecursiveArray<T>: Sized + AsRef<[T]> + AsMut<[T]> {
     }
 
     /// appends a recrusive array to the back of this array.
+    ///
+    /// # Examples
+    ///
+    /// the length of the resulting array is available through its `LENGTH` constant, which can be used to statically name
+    /// the length of the regular array that it is converted to:
+    ///
+    /// ```
+    /// use recursive_array::{RecursiveArray, RecursiveArrayArrayWrapper, RecursiveArrayConcatenation};
+    ///
+    /// type Combined = RecursiveArrayConcatenation<
+    ///     i32,
+    ///     RecursiveArrayArrayWrapper<2, i32>,
+    ///     RecursiveArrayArrayWrapper<3, i32>,
+    /// >;
+    ///
+    /// let a = RecursiveArrayArrayWrapper::new([1, 2]);
+    /// let b = RecursiveArrayArrayWrapper::new([3, 4, 5]);
+    /// let combined: Combined = a.append_back(b);
+    /// let array: [i32; <Combined as RecursiveArray<i32>>::LENGTH] = combined.to_array();
+    /// assert_eq!(array, [1, 2, 3, 4, 5]);
+    /// ```
     fn append_back<R: RecursiveArray<T>>(
         self,
         array: R,
@@ -128,207 +427,3859 @@ pub unsafe trait RecursiveArray<T>: Sized + AsRef<[T]> + AsMut<[T]> {
     ) -> RecursiveArrayConcatenation<T, R, Self> {
         RecursiveArrayConcatenation::new(array, self)
     }
-}
 
-/// an empty recrusive array.
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Default)]
-pub struct EmptyRecursiveArray;
-impl<T> AsRef<[T]> for EmptyRecursiveArray {
-    fn as_ref(&self) -> &[T] {
-        &[]
-    }
-}
-impl<T> AsMut<[T]> for EmptyRecursiveArray {
-    fn as_mut(&mut self) -> &mut [T] {
-        &mut []
+    /// appends a regular array (`[T; M]`) to the back of this array.
+    fn concat_array<const M: usize>(
+        self,
+        array: [T; M],
+    ) -> RecursiveArrayConcatenation<T, Self, RecursiveArrayArrayWrapper<M, T>> {
+        RecursiveArrayConcatenation::new(self, RecursiveArrayArrayWrapper::new(array))
     }
-}
-unsafe impl<T> RecursiveArray<T> for EmptyRecursiveArray {
-    const LENGTH: usize = 0;
-}
 
-/// a recursive array with a single item.
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Default)]
-#[repr(transparent)]
-pub struct RecursiveArraySingleItem<T> {
-    item: T,
-}
-impl<T> AsRef<[T]> for RecursiveArraySingleItem<T> {
-    fn as_ref(&self) -> &[T] {
-        self.as_slice()
+    /// appends a regular array (`[T; M]`) to the front of this array.
+    fn prepend_array<const M: usize>(
+        self,
+        array: [T; M],
+    ) -> RecursiveArrayConcatenation<T, RecursiveArrayArrayWrapper<M, T>, Self> {
+        RecursiveArrayConcatenation::new(RecursiveArrayArrayWrapper::new(array), self)
     }
-}
-impl<T> AsMut<[T]> for RecursiveArraySingleItem<T> {
-    fn as_mut(&mut self) -> &mut [T] {
-        self.as_mut_slice()
+
+    /// extends this array with the elements of the given regular array (`[T; M]`), appending them to its back.
+    /// since the length of a recursive array is part of its type, this returns a new array type whose length is greater than
+    /// the length of this array by `M`. this is equivalent to [`concat_array`](RecursiveArray::concat_array).
+    fn extend_with<const M: usize>(
+        self,
+        items: [T; M],
+    ) -> RecursiveArrayConcatenation<T, Self, RecursiveArrayArrayWrapper<M, T>> {
+        self.concat_array(items)
     }
-}
-unsafe impl<T> RecursiveArray<T> for RecursiveArraySingleItem<T> {
-    const LENGTH: usize = 1;
-}
-impl<T> RecursiveArraySingleItem<T> {
-    /// creates a new recrusive array with a single item.
-    pub fn new(item: T) -> Self {
-        Self { item }
+
+    /// returns an array of the differences between each pair of consecutive elements of this array, such that the `i`-th
+    /// element of the result is `self[i + 1] - self[i]`.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if the length of the output array (`N`) is not equal to `Self::LENGTH - 1` (or to `0` if this
+    /// array is empty). this condition currently can't be checked at compile time due to the limitation of const generics.
+    fn diff<const N: usize>(&self) -> RecursiveArrayArrayWrapper<N, T>
+    where
+        T: Sub<Output = T> + Copy,
+    {
+        self.pairwise(|a, b| *b - *a)
     }
-}
 
-/// a recursive array which concatenates 2 recursive arrays.
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Default)]
-#[repr(C)]
-pub struct RecursiveArrayConcatenation<T, A: RecursiveArray<T>, B: RecursiveArray<T>> {
-    a: A,
-    b: B,
-    phantom: PhantomData<T>,
-}
-impl<T, A: RecursiveArray<T>, B: RecursiveArray<T>> AsRef<[T]>
-    for RecursiveArrayConcatenation<T, A, B>
-{
-    fn as_ref(&self) -> &[T] {
-        self.as_slice()
+    /// applies the given function to each pair of consecutive elements of this array, such that the `i`-th element of the
+    /// result is `f(&self[i], &self[i + 1])`. the result is empty if this array has less than 2 elements.
+    ///
+    /// [`diff`](RecursiveArray::diff) is the special case of this function where the pairs are subtracted.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if the length of the output array (`N`) is not equal to `Self::LENGTH - 1` (or to `0` if this
+    /// array is empty). this condition currently can't be checked at compile time due to the limitation of const generics.
+    fn pairwise<V, F: FnMut(&T, &T) -> V, const N: usize>(
+        &self,
+        mut f: F,
+    ) -> RecursiveArrayArrayWrapper<N, V> {
+        check_output_length::<N>(Self::LENGTH.saturating_sub(1));
+        let slice = self.as_slice();
+        RecursiveArrayArrayWrapper::new(core::array::from_fn(|i| f(&slice[i], &slice[i + 1])))
     }
-}
-impl<T, A: RecursiveArray<T>, B: RecursiveArray<T>> AsMut<[T]>
-    for RecursiveArrayConcatenation<T, A, B>
-{
-    fn as_mut(&mut self) -> &mut [T] {
-        self.as_mut_slice()
+
+    /// returns a recursive array whose memory is all zeroes.
+    #[cfg(feature = "bytemuck")]
+    fn zeroed() -> Self
+    where
+        T: bytemuck::Zeroable,
+    {
+        // the memory representation of `Self` is just `LENGTH` consecutive elements of type `T`, which are all valid when zeroed.
+        unsafe { core::mem::zeroed() }
     }
-}
-unsafe impl<T, A: RecursiveArray<T>, B: RecursiveArray<T>> RecursiveArray<T>
-    for RecursiveArrayConcatenation<T, A, B>
-{
-    const LENGTH: usize = A::LENGTH + B::LENGTH;
-}
-impl<T, A: RecursiveArray<T>, B: RecursiveArray<T>> RecursiveArrayConcatenation<T, A, B> {
-    /// creates a new recrusive array which concatenates the 2 given recursive arrays.
-    pub fn new(a: A, b: B) -> Self {
-        Self {
-            a,
-            b,
-            phantom: PhantomData,
+
+    /// reinterprets the memory of this array as a recursive array wrapper of `M` elements of type `U`, without copying it.
+    ///
+    /// this requires the total size of this array (`Self::LENGTH * size_of::<T>()`) to be equal to `M * size_of::<U>()`,
+    /// and requires this array to be aligned to `align_of::<U>()`. note that an array of `T` is only guaranteed to be
+    /// aligned to `align_of::<T>()`, so if `U` has a greater alignment than `T`, whether the alignment requirement is met
+    /// depends on where this array happens to be placed in memory.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if the size or the alignment requirements are not met. see
+    /// [`try_reinterpret`](RecursiveArray::try_reinterpret) for a non-panicking version.
+    #[cfg(feature = "bytemuck")]
+    fn reinterpret<U: bytemuck::Pod, const M: usize>(&self) -> &RecursiveArrayArrayWrapper<M, U>
+    where
+        T: bytemuck::Pod,
+    {
+        match self.try_reinterpret() {
+            Ok(reinterpreted) => reinterpreted,
+            Err(error) => panic!("failed to reinterpret a recursive array: {}", error),
         }
     }
-}
-impl<T, A: RecursiveArray<T>> RecursiveArrayConcatenation<T, A, RecursiveArraySingleItem<T>> {
-    /// pops an element from the back of this array.
-    /// returns a tuple whose first element is the popped item and its second element is the rest of the array.
-    pub fn pop_back(self) -> (T, A) {
-        (self.b.item, self.a)
+
+    /// reinterprets the memory of this array as a recursive array wrapper of `M` elements of type `U`, without copying it.
+    ///
+    /// returns an error if the total size of this array (`Self::LENGTH * size_of::<T>()`) is not equal to
+    /// `M * size_of::<U>()`, or if this array is not aligned to `align_of::<U>()`. see
+    /// [`reinterpret`](RecursiveArray::reinterpret) for more details about the alignment requirement.
+    #[cfg(feature = "bytemuck")]
+    fn try_reinterpret<U: bytemuck::Pod, const M: usize>(
+        &self,
+    ) -> Result<&RecursiveArrayArrayWrapper<M, U>, CastError>
+    where
+        T: bytemuck::Pod,
+    {
+        RecursiveArrayArrayWrapper::from_bytes(bytemuck::cast_slice(self.as_slice()))
     }
-}
 
-impl<T, B: RecursiveArray<T>> RecursiveArrayConcatenation<T, RecursiveArraySingleItem<T>, B> {
-    /// pops an element from the front of this array.
-    /// returns a tuple whose first element is the popped item and its second element is the rest of the array.
-    pub fn pop_front(self) -> (T, B) {
-        (self.a.item, self.b)
+    /// returns a 1-dimensional `ndarray` view of the elements of this array, without copying them.
+    #[cfg(feature = "ndarray")]
+    fn as_ndarray(&self) -> ndarray::ArrayView1<'_, T> {
+        ndarray::ArrayView1::from(self.as_slice())
     }
-}
 
-/// a recursive array wrapper which wraps a regular rust array (`[T; N]`) and allows it to be treated as a recursive array.
-#[derive(Debug, Clone, Hash, PartialEq, Eq)]
-#[repr(transparent)]
-pub struct RecursiveArrayArrayWrapper<const N: usize, T> {
-    array: [T; N],
-}
-impl<const N: usize, T> RecursiveArrayArrayWrapper<N, T> {
-    /// creates a new recursive array wrapper which wraps the given array.
-    pub fn new(array: [T; N]) -> Self {
-        Self { array }
+    /// returns a mutable 1-dimensional `ndarray` view of the elements of this array, without copying them.
+    #[cfg(feature = "ndarray")]
+    fn as_ndarray_mut(&mut self) -> ndarray::ArrayViewMut1<'_, T> {
+        ndarray::ArrayViewMut1::from(self.as_mut_slice())
     }
-}
-impl<const N: usize, T> AsRef<[T]> for RecursiveArrayArrayWrapper<N, T> {
-    fn as_ref(&self) -> &[T] {
-        self.as_slice()
+
+    /// maps each element of this array using the given function, processing the elements in parallel using `rayon`.
+    /// the order of the elements in the returned array matches the order of the elements in this array.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if the length of the output array (`N`) is not equal to `Self::LENGTH`.
+    /// this condition currently can't be checked at compile time due to the limitation of const generics.
+    #[cfg(feature = "rayon")]
+    fn par_map<U, F, const N: usize>(self, f: F) -> RecursiveArrayArrayWrapper<N, U>
+    where
+        T: Send,
+        U: Send,
+        F: Fn(T) -> U + Sync + Send,
+    {
+        use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+        check_output_length::<N>(Self::LENGTH);
+        let mapped: std::vec::Vec<U> = self.to_array::<N>().into_par_iter().map(f).collect();
+        match mapped.try_into() {
+            Ok(array) => RecursiveArrayArrayWrapper::new(array),
+            Err(_) => unreachable!("a parallel map always preserves the amount of elements"),
+        }
     }
-}
-impl<const N: usize, T> AsMut<[T]> for RecursiveArrayArrayWrapper<N, T> {
-    fn as_mut(&mut self) -> &mut [T] {
-        self.as_mut_slice()
+
+    /// returns an iterator over all overlapping windows of `K` consecutive elements of this array, where each window is a
+    /// reference to a regular array (`[T; K]`). if this array is shorter than `K`, the iterator yields no windows.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if `K` is `0`.
+    fn windows_array<'a, const K: usize>(&'a self) -> impl Iterator<Item = &'a [T; K]>
+    where
+        T: 'a,
+    {
+        self.as_slice()
+            .windows(K)
+            .map(|window| unsafe { &*window.as_ptr().cast::<[T; K]>() })
     }
-}
-unsafe impl<const N: usize, T> RecursiveArray<T> for RecursiveArrayArrayWrapper<N, T> {
-    const LENGTH: usize = N;
-}
 
-/// a recursive array which multiplies the given inner recursive array type `N` times.
-#[derive(Debug, Clone, Hash, PartialEq, Eq)]
-#[repr(transparent)]
-pub struct RecursiveArrayMultiplier<const N: usize, T, A: RecursiveArray<T>> {
+    /// returns an iterator over the non-overlapping groups of `K` consecutive elements of this array, where each group is a
+    /// reference to a regular array (`[T; K]`). if `Self::LENGTH` is not a multiple of `K`, the trailing elements which
+    /// don't form a whole group are not yielded.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if `K` is `0`.
+    fn as_tuples<'a, const K: usize>(&'a self) -> impl Iterator<Item = &'a [T; K]>
+    where
+        T: 'a,
+    {
+        self.as_slice()
+            .chunks_exact(K)
+            .map(|chunk| unsafe { &*chunk.as_ptr().cast::<[T; K]>() })
+    }
+
+    /// returns an iterator which moves the elements out of this array, starting from the last element and ending with the
+    /// first. elements which were not yielded by the iterator are dropped when the iterator is dropped.
+    fn into_iter_rev(self) -> core::iter::Rev<RecursiveArrayIntoIter<T, Self>> {
+        RecursiveArrayIntoIter::new(self).rev()
+    }
+
+    /// converts this array to a regular array (`[T; N]`) using [`to_array`](RecursiveArray::to_array), and returns the
+    /// standard iterator which moves the elements out of it.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if the length of the array (`N`) is not equal to `Self::LENGTH`.
+    /// this condition currently can't be checked at compile time due to the limitation of const generics.
+    fn into_std_iter<const N: usize>(self) -> core::array::IntoIter<T, N> {
+        self.to_array::<N>().into_iter()
+    }
+
+    /// reduces the elements of this array to a single element by repeatedly applying the given fallible function, stopping
+    /// at the first error. the elements which were not yet consumed when an error occurs are dropped.
+    ///
+    /// returns `Ok(None)` if this array is empty.
+    fn try_reduce<E, F: FnMut(T, T) -> Result<T, E>>(self, mut f: F) -> Result<Option<T>, E> {
+        let mut elements = RecursiveArrayIntoIter::new(self);
+        let Some(mut result) = elements.next() else {
+            return Ok(None);
+        };
+        for element in elements {
+            result = f(result, element)?;
+        }
+        Ok(Some(result))
+    }
+
+    /// returns a reference to the last `K` elements of this array as a regular array (`[T; K]`).
+    ///
+    /// # Panics
+    ///
+    /// this function panics if `K` is greater than `Self::LENGTH`.
+    fn suffix<const K: usize>(&self) -> &[T; K] {
+        match self.try_suffix() {
+            Some(suffix) => suffix,
+            None => panic!(
+                "tried to get the last {} elements of a recursive array of length {}",
+                K,
+                Self::LENGTH,
+            ),
+        }
+    }
+
+    /// returns a mutable reference to the last `K` elements of this array as a regular array (`[T; K]`).
+    ///
+    /// # Panics
+    ///
+    /// this function panics if `K` is greater than `Self::LENGTH`.
+    fn suffix_mut<const K: usize>(&mut self) -> &mut [T; K] {
+        match self.try_suffix_mut() {
+            Some(suffix) => suffix,
+            None => panic!(
+                "tried to get the last {} elements of a recursive array of length {}",
+                K,
+                Self::LENGTH,
+            ),
+        }
+    }
+
+    /// returns a reference to the last `K` elements of this array as a regular array (`[T; K]`), or `None` if `K` is
+    /// greater than `Self::LENGTH`.
+    fn try_suffix<const K: usize>(&self) -> Option<&[T; K]> {
+        self.as_slice().last_chunk()
+    }
+
+    /// returns a mutable reference to the last `K` elements of this array as a regular array (`[T; K]`), or `None` if `K`
+    /// is greater than `Self::LENGTH`.
+    fn try_suffix_mut<const K: usize>(&mut self) -> Option<&mut [T; K]> {
+        self.as_mut_slice().last_chunk_mut()
+    }
+
+    /// returns an iterator over every `step`-th element of this array, starting from the first element.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if `step` is `0`.
+    fn step_by(&self, step: usize) -> core::iter::StepBy<core::slice::Iter<'_, T>> {
+        self.as_slice().iter().step_by(step)
+    }
+
+    /// collects clones of every `step`-th element of this array, starting from the first element, into a new array.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if `step` is `0`, or if the length of the output array (`OUT`) is not equal to the amount of
+    /// collected elements, which is `Self::LENGTH / step` rounded up.
+    fn stride_collect<const OUT: usize>(&self, step: usize) -> RecursiveArrayArrayWrapper<OUT, T>
+    where
+        T: Clone,
+    {
+        if step == 0 {
+            panic!("tried to collect every 0th element of a recursive array");
+        }
+        check_output_length::<OUT>(Self::LENGTH.div_ceil(step));
+        let slice = self.as_slice();
+        RecursiveArrayArrayWrapper::new(core::array::from_fn(|i| slice[i * step].clone()))
+    }
+
+    /// returns an array of the inclusive prefix sums of this array, such that the `i`-th element of the result is the sum of
+    /// the first `i + 1` elements of this array. this is the inverse of [`diff`](RecursiveArray::diff).
+    ///
+    /// # Panics
+    ///
+    /// this function panics if the length of the output array (`N`) is not equal to `Self::LENGTH`.
+    /// this condition currently can't be checked at compile time due to the limitation of const generics.
+    fn cumsum<const N: usize>(&self) -> RecursiveArrayArrayWrapper<N, T>
+    where
+        T: Add<Output = T> + Copy,
+    {
+        check_output_length::<N>(Self::LENGTH);
+        let slice = self.as_slice();
+        let mut sum: Option<T> = None;
+        RecursiveArrayArrayWrapper::new(core::array::from_fn(|i| {
+            let next = match sum {
+                Some(sum) => sum + slice[i],
+                None => slice[i],
+            };
+            sum = Some(next);
+            next
+        }))
+    }
+
+    /// clamps each element of this array, in place, into the range `[min, max]`.
+    ///
+    /// this follows the semantics of [`f32::clamp`] for float elements: elements which are NaN are left as NaN.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if `min` is greater than `max`, or if either of them is NaN.
+    fn clamp_elements(&mut self, min: T, max: T)
+    where
+        T: PartialOrd + Copy,
+    {
+        check_clamp_bounds(min, max);
+        for element in self.as_mut_slice() {
+            *element = clamp_element(*element, min, max);
+        }
+    }
+
+    /// returns an array of the elements of this array, each clamped into the range `[min, max]`.
+    ///
+    /// this follows the semantics of [`f32::clamp`] for float elements: elements which are NaN are left as NaN.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if `min` is greater than `max`, or if either of them is NaN. it also panics if the length of the
+    /// output array (`N`) is not equal to `Self::LENGTH`. this condition currently can't be checked at compile time due to the
+    /// limitation of const generics.
+    fn clamped<const N: usize>(&self, min: T, max: T) -> RecursiveArrayArrayWrapper<N, T>
+    where
+        T: PartialOrd + Copy,
+    {
+        check_clamp_bounds(min, max);
+        check_output_length::<N>(Self::LENGTH);
+        let slice = self.as_slice();
+        RecursiveArrayArrayWrapper::new(core::array::from_fn(|i| clamp_element(slice[i], min, max)))
+    }
+
+    /// returns the euclidean length of this array when treated as a vector, which is the square root of the sum of the
+    /// squares of its elements.
+    #[cfg(feature = "libm")]
+    fn length(&self) -> T
+    where
+        T: Float,
+    {
+        self.as_slice()
+            .iter()
+            .fold(T::ZERO, |sum, element| sum + *element * *element)
+            .sqrt()
+    }
+
+    /// returns an array of the elements of this array divided by its euclidean [`length`](RecursiveArray::length), such that
+    /// the length of the result is `1`. if the length of this array is zero, the elements are returned unchanged, so the
+    /// result is also a zero vector.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if the length of the output array (`N`) is not equal to `Self::LENGTH`.
+    /// this condition currently can't be checked at compile time due to the limitation of const generics.
+    #[cfg(feature = "libm")]
+    fn normalize<const N: usize>(&self) -> RecursiveArrayArrayWrapper<N, T>
+    where
+        T: Float,
+    {
+        check_output_length::<N>(Self::LENGTH);
+        let length = self.length();
+        let slice = self.as_slice();
+        if length == T::ZERO {
+            return RecursiveArrayArrayWrapper::new(core::array::from_fn(|i| slice[i]));
+        }
+        RecursiveArrayArrayWrapper::new(core::array::from_fn(|i| slice[i] / length))
+    }
+
+    /// returns the arithmetic mean of the elements of this array. returns NaN if this array is empty.
+    ///
+    /// the elements are summed sequentially, so for long arrays, or for elements of very different magnitudes, the result
+    /// may suffer from accumulated rounding errors.
+    fn mean(&self) -> T
+    where
+        T: Float,
+    {
+        let sum = self
+            .as_slice()
+            .iter()
+            .fold(T::ZERO, |sum, element| sum + *element);
+        sum / T::from_usize(Self::LENGTH)
+    }
+
+    /// returns the population variance of the elements of this array, which is the mean of the squared differences of the
+    /// elements from their [`mean`](RecursiveArray::mean). returns NaN if this array is empty.
+    ///
+    /// the variance is computed in two passes, first computing the mean and then summing the squared differences
+    /// sequentially, so it is subject to the same precision limits as [`mean`](RecursiveArray::mean).
+    fn variance(&self) -> T
+    where
+        T: Float,
+    {
+        let mean = self.mean();
+        let sum = self.as_slice().iter().fold(T::ZERO, |sum, element| {
+            let difference = *element - mean;
+            sum + difference * difference
+        });
+        sum / T::from_usize(Self::LENGTH)
+    }
+
+    /// returns the moving average of this array over windows of `W` consecutive elements, such that the `i`-th element of
+    /// the result is the [`mean`](RecursiveArray::mean) of the window starting at index `i`.
+    ///
+    /// each window is summed separately, instead of maintaining a running sum, so that rounding errors don't accumulate
+    /// across windows.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if `W` is `0` or greater than `Self::LENGTH`, or if the length of the output array (`N`) is not
+    /// equal to `Self::LENGTH - W + 1`. these conditions currently can't be checked at compile time due to the limitation of
+    /// const generics.
+    fn moving_average<const W: usize, const N: usize>(&self) -> RecursiveArrayArrayWrapper<N, T>
+    where
+        T: Float,
+    {
+        if W == 0 || W > Self::LENGTH {
+            panic!(
+                "tried to average windows of length {} of a recursive array of length {}",
+                W,
+                Self::LENGTH,
+            );
+        }
+        check_output_length::<N>(Self::LENGTH - W + 1);
+        let slice = self.as_slice();
+        let window_length = T::from_usize(W);
+        RecursiveArrayArrayWrapper::new(core::array::from_fn(|i| {
+            let sum = slice[i..i + W]
+                .iter()
+                .fold(T::ZERO, |sum, element| sum + *element);
+            sum / window_length
+        }))
+    }
+
+    /// returns the full discrete convolution of this array with the given kernel, such that the `k`-th element of the
+    /// result is the sum of `self[i] * kernel[j]` over all `i` and `j` where `i + j == k`.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if the length of the output array (`OUT`) is not equal to `Self::LENGTH + M - 1` (or to `0` if
+    /// this array or the kernel is empty). this condition currently can't be checked at compile time due to the limitation
+    /// of const generics.
+    fn convolve<const M: usize, const OUT: usize>(
+        &self,
+        kernel: &RecursiveArrayArrayWrapper<M, T>,
+    ) -> RecursiveArrayArrayWrapper<OUT, T>
+    where
+        T: Mul<Output = T> + Add<Output = T> + Default + Copy,
+    {
+        let expected = if Self::LENGTH == 0 || M == 0 {
+            0
+        } else {
+            Self::LENGTH + M - 1
+        };
+        check_output_length::<OUT>(expected);
+        let slice = self.as_slice();
+        let kernel = kernel.as_slice();
+        RecursiveArrayArrayWrapper::new(core::array::from_fn(|k| {
+            // the indexes `i` of this array for which `k - i` is a valid index of the kernel.
+            let start = k.saturating_sub(M - 1);
+            let end = k.min(Self::LENGTH - 1);
+            (start..=end).fold(T::default(), |sum, i| sum + slice[i] * kernel[k - i])
+        }))
+    }
+
+    /// returns the elements of this array as a mutable slice of possibly uninitialized elements.
+    ///
+    /// # Safety
+    ///
+    /// the caller must make sure that all elements of this array are initialized once the returned slice is no longer used,
+    /// for example by not writing [`MaybeUninit::uninit`](core::mem::MaybeUninit::uninit) to any of its elements. also note
+    /// that overwriting an element through the returned slice doesn't drop its previous value.
+    unsafe fn as_uninit_mut(&mut self) -> &mut [core::mem::MaybeUninit<T>] {
+        let slice = self.as_mut_slice();
+        unsafe { core::slice::from_raw_parts_mut(slice.as_mut_ptr().cast(), slice.len()) }
+    }
+
+    /// clones the elements of this array into a new recursive array wrapper, regardless of the structure of this array.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if the length of the output array (`N`) is not equal to `Self::LENGTH`.
+    /// this condition currently can't be checked at compile time due to the limitation of const generics.
+    fn to_wrapper<const N: usize>(&self) -> RecursiveArrayArrayWrapper<N, T>
+    where
+        T: Clone,
+    {
+        check_output_length::<N>(Self::LENGTH);
+        let slice = self.as_slice();
+        RecursiveArrayArrayWrapper::new(core::array::from_fn(|i| slice[i].clone()))
+    }
+
+    /// clones the values which the elements of this array reference into a new recursive array wrapper, for arrays whose
+    /// elements are references (or other pointer types) to values of type `U`.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if the length of the output array (`N`) is not equal to `Self::LENGTH`.
+    /// this condition currently can't be checked at compile time due to the limitation of const generics.
+    fn cloned<U: Clone, const N: usize>(&self) -> RecursiveArrayArrayWrapper<N, U>
+    where
+        T: core::ops::Deref<Target = U>,
+    {
+        check_output_length::<N>(Self::LENGTH);
+        let slice = self.as_slice();
+        RecursiveArrayArrayWrapper::new(core::array::from_fn(|i| (*slice[i]).clone()))
+    }
+
+    /// copies the values which the elements of this array reference into a new recursive array wrapper, for arrays whose
+    /// elements are references (or other pointer types) to values of type `U`.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if the length of the output array (`N`) is not equal to `Self::LENGTH`.
+    /// this condition currently can't be checked at compile time due to the limitation of const generics.
+    fn copied<U: Copy, const N: usize>(&self) -> RecursiveArrayArrayWrapper<N, U>
+    where
+        T: core::ops::Deref<Target = U>,
+    {
+        check_output_length::<N>(Self::LENGTH);
+        let slice = self.as_slice();
+        RecursiveArrayArrayWrapper::new(core::array::from_fn(|i| *slice[i]))
+    }
+
+    /// splits this array into two arrays, moving its first `K` elements into the first array and the remaining `R`
+    /// elements into the second array.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if `K + R` is not equal to `Self::LENGTH`.
+    /// this condition currently can't be checked at compile time due to the limitation of const generics.
+    fn take_prefix<const K: usize, const R: usize>(
+        self,
+    ) -> (
+        RecursiveArrayArrayWrapper<K, T>,
+        RecursiveArrayArrayWrapper<R, T>,
+    ) {
+        if K.checked_add(R) != Some(Self::LENGTH) {
+            panic!(
+                "tried to split a recursive array of length {} into arrays of lengths {} and {}",
+                Self::LENGTH,
+                K,
+                R,
+            );
+        }
+        // the elements are moved out of the array, so it must not drop them.
+        let array = core::mem::ManuallyDrop::new(self);
+        let ptr = array.as_slice().as_ptr();
+        unsafe {
+            (
+                RecursiveArrayArrayWrapper::new(core::ptr::read(ptr.cast::<[T; K]>())),
+                RecursiveArrayArrayWrapper::new(core::ptr::read(ptr.add(K).cast::<[T; R]>())),
+            )
+        }
+    }
+
+    /// separates the frame-interleaved samples of `CH` channels stored in this array (for example `[l, r, l, r, ...]`) into
+    /// a multiplier containing a contiguous array of `LEN` samples for each channel.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if `CH` is `0`, if `Self::LENGTH` is not a multiple of `CH`, or if the length of each channel
+    /// array (`LEN`) is not equal to `Self::LENGTH / CH`. these conditions currently can't be checked at compile time due to
+    /// the limitation of const generics.
+    fn deinterleave_channels<const CH: usize, const LEN: usize>(
+        &self,
+    ) -> RecursiveArrayMultiplier<CH, T, RecursiveArrayArrayWrapper<LEN, T>>
+    where
+        T: Copy,
+    {
+        if CH == 0 || Self::LENGTH % CH != 0 {
+            panic!(
+                "tried to deinterleave a recursive array of length {} into {} channels",
+                Self::LENGTH,
+                CH,
+            );
+        }
+        check_output_length::<LEN>(Self::LENGTH / CH);
+        let slice = self.as_slice();
+        RecursiveArrayMultiplier::new(core::array::from_fn(|channel| {
+            RecursiveArrayArrayWrapper::new(core::array::from_fn(|frame| {
+                slice[frame * CH + channel]
+            }))
+        }))
+    }
+
+    /// returns an array of the element-wise minimum of this array and the given array, such that the `i`-th element of the
+    /// result is the smaller of `self[i]` and `other[i]`. if the two elements can't be compared, the element of this array
+    /// is used.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if the length of the other array or the length of the output array (`N`) is not
+    /// equal to `Self::LENGTH`. this condition currently can't be checked at compile time due to the limitation of const
+    /// generics.
+    fn elementwise_min<const N: usize>(
+        &self,
+        other: &impl RecursiveArray<T>,
+    ) -> RecursiveArrayArrayWrapper<N, T>
+    where
+        T: PartialOrd + Copy,
+    {
+        zip_elements(
+            self.as_slice(),
+            other.as_slice(),
+            |a, b| if b < a { b } else { a },
+        )
+    }
+
+    /// returns an array of the element-wise maximum of this array and the given array, such that the `i`-th element of the
+    /// result is the greater of `self[i]` and `other[i]`. if the two elements can't be compared, the element of this array
+    /// is used.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if the length of the other array or the length of the output array (`N`) is not
+    /// equal to `Self::LENGTH`. this condition currently can't be checked at compile time due to the limitation of const
+    /// generics.
+    fn elementwise_max<const N: usize>(
+        &self,
+        other: &impl RecursiveArray<T>,
+    ) -> RecursiveArrayArrayWrapper<N, T>
+    where
+        T: PartialOrd + Copy,
+    {
+        zip_elements(
+            self.as_slice(),
+            other.as_slice(),
+            |a, b| if b > a { b } else { a },
+        )
+    }
+
+    integer_elementwise_methods!(
+        wrapping_add => "wrapping sum",
+        wrapping_sub => "wrapping difference",
+        wrapping_mul => "wrapping product",
+        saturating_add => "saturating sum",
+        saturating_sub => "saturating difference",
+        saturating_mul => "saturating product",
+    );
+
+    /// returns whether each element of this array differs from the corresponding element of the given array by at most
+    /// `epsilon`. returns `false` if the arrays are not of the same length.
+    fn approx_eq(&self, other: &impl RecursiveArray<T>, epsilon: T) -> bool
+    where
+        T: Float,
+    {
+        self.len() == other.len()
+            && self
+                .as_slice()
+                .iter()
+                .zip(other.as_slice())
+                .all(|(a, b)| (*a - *b).abs() <= epsilon)
+    }
+
+    /// returns whether each element of this array differs from the corresponding element of the given array by at most
+    /// `tolerance` times the greater of their absolute values. returns `false` if the arrays are not of the same length.
+    fn approx_eq_rel(&self, other: &impl RecursiveArray<T>, tolerance: T) -> bool
+    where
+        T: Float,
+    {
+        self.len() == other.len()
+            && self.as_slice().iter().zip(other.as_slice()).all(|(a, b)| {
+                let magnitude = if a.abs() > b.abs() { a.abs() } else { b.abs() };
+                a == b || (*a - *b).abs() <= tolerance * magnitude
+            })
+    }
+
+    /// returns the index of the maximum element of this array. if several elements are equally maximal, the index of the
+    /// first one is returned. elements which can't be compared to themselves, such as NaN, are skipped, so `None` is
+    /// returned if this array is empty or if all of its elements are skipped.
+    fn argmax(&self) -> Option<usize>
+    where
+        T: PartialOrd,
+    {
+        let slice = self.as_slice();
+        let first = first_comparable_index(slice)?;
+        Some((first + 1..slice.len()).fold(
+            first,
+            |best, i| {
+                if slice[i] > slice[best] {
+                    i
+                } else {
+                    best
+                }
+            },
+        ))
+    }
+
+    /// returns the index of the minimum element of this array. if several elements are equally minimal, the index of the
+    /// first one is returned. elements which can't be compared to themselves, such as NaN, are skipped, so `None` is
+    /// returned if this array is empty or if all of its elements are skipped.
+    fn argmin(&self) -> Option<usize>
+    where
+        T: PartialOrd,
+    {
+        let slice = self.as_slice();
+        let first = first_comparable_index(slice)?;
+        Some((first + 1..slice.len()).fold(
+            first,
+            |best, i| {
+                if slice[i] < slice[best] {
+                    i
+                } else {
+                    best
+                }
+            },
+        ))
+    }
+
+    /// splits this array into its first element and an array of the remaining elements, or returns `None` if this array is
+    /// empty.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if this array is not empty and the length of the output array (`M`) is not equal to
+    /// `Self::LENGTH - 1`. this condition currently can't be checked at compile time due to the limitation of const generics.
+    fn uncons<const M: usize>(self) -> Option<(T, RecursiveArrayArrayWrapper<M, T>)> {
+        if Self::LENGTH == 0 {
+            return None;
+        }
+        check_output_length::<M>(Self::LENGTH - 1);
+        let (head, tail) = self.take_prefix::<1, M>();
+        let [head] = head.array;
+        Some((head, tail))
+    }
+
+    /// returns an array of clones of the elements of this array at the given indices, such that the `i`-th element of the
+    /// result is `self[indices[i]]`.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if any of the indices is out of the bounds of this array.
+    fn gather<const K: usize>(&self, indices: &[usize; K]) -> RecursiveArrayArrayWrapper<K, T>
+    where
+        T: Clone,
+    {
+        let slice = self.as_slice();
+        RecursiveArrayArrayWrapper::new(core::array::from_fn(|i| {
+            check_index(indices[i], Self::LENGTH);
+            slice[indices[i]].clone()
+        }))
+    }
+
+    /// writes clones of the given values to the elements of this array at the given indices, such that `self[indices[i]]` is
+    /// set to `values[i]`. if an index appears more than once, the last value written to it wins.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if the amount of indices is not equal to the amount of values, or if any of the indices is out
+    /// of the bounds of this array. in both cases, this array is left unmodified.
+    fn scatter(&mut self, indices: &[usize], values: &[T])
+    where
+        T: Clone,
+    {
+        if indices.len() != values.len() {
+            panic!(
+                "tried to scatter {} values to {} indices",
+                values.len(),
+                indices.len(),
+            );
+        }
+        for index in indices {
+            check_index(*index, Self::LENGTH);
+        }
+        let slice = self.as_mut_slice();
+        for (index, value) in indices.iter().zip(values) {
+            slice[*index] = value.clone();
+        }
+    }
+
+    /// combines the elements of this array with the elements of the given array using the given function, consuming both
+    /// arrays, such that the `i`-th element of the result is `f(self[i], other[i])`.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if the length of the other array (`O::LENGTH`) or the length of the output array (`N`) is not
+    /// equal to `Self::LENGTH`. this condition currently can't be checked at compile time due to the limitation of const
+    /// generics.
+    fn zip_map<U, V, O: RecursiveArray<U>, F: FnMut(T, U) -> V, const N: usize>(
+        self,
+        other: O,
+        mut f: F,
+    ) -> RecursiveArrayArrayWrapper<N, V> {
+        check_same_length(Self::LENGTH, O::LENGTH);
+        check_output_length::<N>(Self::LENGTH);
+        let mut elements = RecursiveArrayIntoIter::new(self);
+        let mut other_elements = RecursiveArrayIntoIter::new(other);
+        RecursiveArrayArrayWrapper::new(core::array::from_fn(|_| {
+            match (elements.next(), other_elements.next()) {
+                (Some(element), Some(other_element)) => f(element, other_element),
+                _ => unreachable!("both arrays have exactly `N` elements"),
+            }
+        }))
+    }
+
+    /// combines the elements of this array with the elements of the given regular array (`[U; M]`) using the given
+    /// function, consuming both arrays, such that the `i`-th element of the result is `f(self[i], other[i])`.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if the length of the other array (`M`) is not equal to `Self::LENGTH`.
+    /// this condition currently can't be checked at compile time due to the limitation of const generics.
+    fn zip_array<U, const M: usize, V, F: FnMut(T, U) -> V>(
+        self,
+        other: [U; M],
+        f: F,
+    ) -> RecursiveArrayArrayWrapper<M, V> {
+        self.zip_map(RecursiveArrayArrayWrapper::new(other), f)
+    }
+
+    /// combines the elements of this array with the elements of the given regular array (`[U; M]`) using the given
+    /// function, consuming both arrays, such that the `i`-th element of the result is `f(self[i], other[i])`.
+    ///
+    /// returns both arrays back, unchanged, if the length of the other array (`M`) is not equal to `Self::LENGTH`.
+    fn try_zip_array<U, const M: usize, V, F: FnMut(T, U) -> V>(
+        self,
+        other: [U; M],
+        f: F,
+    ) -> Result<RecursiveArrayArrayWrapper<M, V>, (Self, [U; M])> {
+        if M != Self::LENGTH {
+            return Err((self, other));
+        }
+        Ok(self.zip_array(other, f))
+    }
+
+    /// reverses the order of the bits of each element of this array, in place.
+    fn reverse_bits(&mut self)
+    where
+        T: Integer,
+    {
+        for element in self.as_mut_slice() {
+            *element = T::reverse_bits(*element);
+        }
+    }
+
+    /// reverses the order of the bytes of each element of this array, in place.
+    fn reverse_bytes(&mut self)
+    where
+        T: Integer,
+    {
+        for element in self.as_mut_slice() {
+            *element = T::swap_bytes(*element);
+        }
+    }
+
+    /// returns an array of the elements of this array, each with the order of its bits reversed.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if the length of the output array (`N`) is not equal to `Self::LENGTH`.
+    /// this condition currently can't be checked at compile time due to the limitation of const generics.
+    fn reversed_bits<const N: usize>(&self) -> RecursiveArrayArrayWrapper<N, T>
+    where
+        T: Integer,
+    {
+        check_output_length::<N>(Self::LENGTH);
+        let slice = self.as_slice();
+        RecursiveArrayArrayWrapper::new(core::array::from_fn(|i| T::reverse_bits(slice[i])))
+    }
+
+    /// returns an array of the elements of this array, each with the order of its bytes reversed.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if the length of the output array (`N`) is not equal to `Self::LENGTH`.
+    /// this condition currently can't be checked at compile time due to the limitation of const generics.
+    fn reversed_bytes<const N: usize>(&self) -> RecursiveArrayArrayWrapper<N, T>
+    where
+        T: Integer,
+    {
+        check_output_length::<N>(Self::LENGTH);
+        let slice = self.as_slice();
+        RecursiveArrayArrayWrapper::new(core::array::from_fn(|i| T::swap_bytes(slice[i])))
+    }
+
+    /// returns an iterator over the elements of this array along with their indices.
+    fn iter_indexed(&self) -> core::iter::Enumerate<core::slice::Iter<'_, T>> {
+        self.as_slice().iter().enumerate()
+    }
+
+    /// returns an iterator over mutable references to the elements of this array along with their indices.
+    fn iter_mut_indexed(&mut self) -> core::iter::Enumerate<core::slice::IterMut<'_, T>> {
+        self.as_mut_slice().iter_mut().enumerate()
+    }
+
+    /// returns the sum of the elements of this array, or `None` if the sum overflows.
+    fn checked_sum(&self) -> Option<T>
+    where
+        T: Integer,
+    {
+        self.as_slice()
+            .iter()
+            .try_fold(T::ZERO, |sum, element| sum.checked_add(*element))
+    }
+
+    /// returns the dot product of this array and the given array, which is the sum of the products of their corresponding
+    /// elements, where both the multiplications and the additions saturate at the numeric bounds instead of overflowing.
+    ///
+    /// since each product is saturated on its own before it is added, the result can be far from the true dot product even
+    /// when the true dot product fits in `T`. for example, the saturating dot product of `[127i8, 2]` and `[2, -100]` is
+    /// `127 + -128 = -1`, while the true dot product is `54`.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if the length of the other array is not equal to `Self::LENGTH`.
+    fn saturating_dot(&self, other: &impl RecursiveArray<T>) -> T
+    where
+        T: Integer,
+    {
+        check_same_length(Self::LENGTH, other.len());
+        self.as_slice()
+            .iter()
+            .zip(other.as_slice())
+            .fold(T::ZERO, |sum, (a, b)| {
+                sum.saturating_add(a.saturating_mul(*b))
+            })
+    }
+
+    /// returns the sum of the elements of this array multiplied by the corresponding weights, which is
+    /// `self[0] * weights[0] + self[1] * weights[1] + ...`. returns `T::default()` if this array is empty.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if the length of the weights array is not equal to `Self::LENGTH`.
+    fn weighted_sum(&self, weights: &impl RecursiveArray<T>) -> T
+    where
+        T: Mul<Output = T> + Add<Output = T> + Default + Copy,
+    {
+        check_same_length(Self::LENGTH, weights.len());
+        self.as_slice()
+            .iter()
+            .zip(weights.as_slice())
+            .fold(T::default(), |sum, (element, weight)| {
+                sum + *element * *weight
+            })
+    }
+
+    /// returns the [`weighted_sum`](RecursiveArray::weighted_sum) of the elements of this array divided by the sum of the
+    /// weights. if the weights sum to zero, the result is whatever dividing by zero results in for `T`, which is NaN or an
+    /// infinity for floats, and a panic for integers.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if the length of the weights array is not equal to `Self::LENGTH`.
+    fn weighted_mean(&self, weights: &impl RecursiveArray<T>) -> T
+    where
+        T: Mul<Output = T> + Add<Output = T> + Div<Output = T> + Default + Copy,
+    {
+        let weights_sum = weights
+            .as_slice()
+            .iter()
+            .fold(T::default(), |sum, weight| sum + *weight);
+        self.weighted_sum(weights) / weights_sum
+    }
+
+    /// returns the total amount of ones in the binary representations of the elements of this array.
+    fn count_ones_total(&self) -> u32
+    where
+        T: Integer,
+    {
+        self.as_slice()
+            .iter()
+            .fold(0, |total, element| total + element.count_ones())
+    }
+
+    /// interprets the elements of this array as fixed-point numbers with `FRAC` fractional bits (Q-format), and returns an
+    /// array of their values as floats, which is each element divided by `2^FRAC`.
+    ///
+    /// see [`from_float_q`](RecursiveArrayArrayWrapper::from_float_q) for the reverse conversion.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if the length of the output array (`N`) is not equal to `Self::LENGTH`.
+    /// this condition currently can't be checked at compile time due to the limitation of const generics.
+    fn to_float_q<const FRAC: usize, const N: usize>(&self) -> RecursiveArrayArrayWrapper<N, f32>
+    where
+        T: Integer,
+    {
+        check_output_length::<N>(Self::LENGTH);
+        let scale = fixed_point_scale(FRAC);
+        let slice = self.as_slice();
+        RecursiveArrayArrayWrapper::new(core::array::from_fn(|i| slice[i].to_f32() / scale))
+    }
+
+    /// returns a reference to the `LEN` elements of this array which start at index `OFFSET`, as a recursive array wrapper.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if `OFFSET + LEN` is greater than `Self::LENGTH`.
+    fn subarray_ref<const OFFSET: usize, const LEN: usize>(
+        &self,
+    ) -> &RecursiveArrayArrayWrapper<LEN, T> {
+        check_subarray_bounds(OFFSET, LEN, Self::LENGTH);
+        RecursiveArrayArrayWrapper::from_slice(&self.as_slice()[OFFSET..OFFSET + LEN])
+    }
+
+    /// returns a mutable reference to the `LEN` elements of this array which start at index `OFFSET`, as a recursive array
+    /// wrapper.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if `OFFSET + LEN` is greater than `Self::LENGTH`.
+    fn subarray_mut<const OFFSET: usize, const LEN: usize>(
+        &mut self,
+    ) -> &mut RecursiveArrayArrayWrapper<LEN, T> {
+        check_subarray_bounds(OFFSET, LEN, Self::LENGTH);
+        RecursiveArrayArrayWrapper::from_mut_slice(&mut self.as_mut_slice()[OFFSET..OFFSET + LEN])
+    }
+
+    /// returns a reference to the first `K` elements of this array, as a recursive array wrapper. this is equivalent to
+    /// [`subarray_ref::<0, K>`](RecursiveArray::subarray_ref).
+    ///
+    /// # Panics
+    ///
+    /// this function panics if `K` is greater than `Self::LENGTH`.
+    fn truncate_ref<const K: usize>(&self) -> &RecursiveArrayArrayWrapper<K, T> {
+        self.subarray_ref::<0, K>()
+    }
+
+    /// returns a mutable reference to the first `K` elements of this array, as a recursive array wrapper. this is equivalent
+    /// to [`subarray_mut::<0, K>`](RecursiveArray::subarray_mut).
+    ///
+    /// # Panics
+    ///
+    /// this function panics if `K` is greater than `Self::LENGTH`.
+    fn truncate_mut<const K: usize>(&mut self) -> &mut RecursiveArrayArrayWrapper<K, T> {
+        self.subarray_mut::<0, K>()
+    }
+
+    /// converts this array to a recursive array wrapper of `M` elements, whose first `Self::LENGTH` elements are the elements
+    /// of this array, and whose remaining `M - Self::LENGTH` elements are clones of the given value. this is the reverse of
+    /// [`truncate_ref`](RecursiveArray::truncate_ref).
+    ///
+    /// # Panics
+    ///
+    /// this function panics if `M` is less than `Self::LENGTH`.
+    /// this condition currently can't be checked at compile time due to the limitation of const generics.
+    fn pad_to<const M: usize>(self, fill: T) -> RecursiveArrayArrayWrapper<M, T>
+    where
+        T: Clone,
+    {
+        if M < Self::LENGTH {
+            panic!(
+                "tried to pad a recursive array of length {} to a length of {}",
+                Self::LENGTH,
+                M,
+            );
+        }
+        let mut elements = RecursiveArrayIntoIter::new(self);
+        RecursiveArrayArrayWrapper::new(core::array::from_fn(|_| {
+            elements.next().unwrap_or_else(|| fill.clone())
+        }))
+    }
+
+    /// returns whether all elements of this array satisfy the given predicate. returns `true` if this array is empty.
+    fn all<F: FnMut(&T) -> bool>(&self, pred: F) -> bool {
+        self.as_slice().iter().all(pred)
+    }
+
+    /// returns whether any element of this array satisfies the given predicate. returns `false` if this array is empty.
+    fn any<F: FnMut(&T) -> bool>(&self, pred: F) -> bool {
+        self.as_slice().iter().any(pred)
+    }
+
+    /// returns the amount of elements of this array which are equal to the given value.
+    fn count(&self, value: &T) -> usize
+    where
+        T: PartialEq,
+    {
+        self.as_slice()
+            .iter()
+            .filter(|element| *element == value)
+            .count()
+    }
+
+    /// returns the amount of elements of this array which satisfy the given predicate.
+    fn count_by<F: FnMut(&T) -> bool>(&self, mut pred: F) -> usize {
+        self.as_slice()
+            .iter()
+            .filter(|element| pred(element))
+            .count()
+    }
+
+    /// returns the length of the longest prefix of this array whose elements all satisfy the given predicate.
+    ///
+    /// since the length of this array is fixed, a length is returned instead of a shorter array, which can then be used to
+    /// split this array, for example using [`subarray_ref`](RecursiveArray::subarray_ref).
+    fn take_while_len<F: FnMut(&T) -> bool>(&self, mut pred: F) -> usize {
+        self.as_slice()
+            .iter()
+            .position(|element| !pred(element))
+            .unwrap_or(Self::LENGTH)
+    }
+
+    /// returns the amount of elements of this array which remain after skipping the longest prefix whose elements all
+    /// satisfy the given predicate, which is `Self::LENGTH - self.take_while_len(pred)`.
+    fn skip_while_len<F: FnMut(&T) -> bool>(&self, pred: F) -> usize {
+        Self::LENGTH - self.take_while_len(pred)
+    }
+
+    /// reorders the elements of this array in place according to the given permutation, such that the element at index `i`
+    /// after reordering is the element which was at index `perm[i]` before reordering.
+    ///
+    /// this doesn't require any extra memory, at the cost of taking quadratic time in the worst case.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if `perm` is not a permutation of `0..Self::LENGTH`.
+    fn apply_permutation(&mut self, perm: &[usize]) {
+        check_permutation(perm, Self::LENGTH);
+        let slice = self.as_mut_slice();
+        for start in 0..perm.len() {
+            // each cycle is only applied once, starting from its smallest index.
+            let mut index = perm[start];
+            while index > start {
+                index = perm[index];
+            }
+            if index != start {
+                continue;
+            }
+            let mut index = start;
+            while perm[index] != start {
+                slice.swap(index, perm[index]);
+                index = perm[index];
+            }
+        }
+    }
+
+    /// splits this array into a reference to its first `K` elements as a regular array (`[T; K]`) and a slice of the
+    /// remaining elements.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if `K` is greater than `Self::LENGTH`.
+    fn split_off_front<const K: usize>(&self) -> (&[T; K], &[T]) {
+        match self.as_slice().split_first_chunk() {
+            Some(split) => split,
+            None => panic!(
+                "tried to get the first {} elements of a recursive array of length {}",
+                K,
+                Self::LENGTH,
+            ),
+        }
+    }
+
+    /// splits this array into a regular array (`[T; K]`) of its first `K` elements and an array of the remaining `R`
+    /// elements, moving the elements out of this array.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if `K + R` is not equal to `Self::LENGTH`.
+    /// this condition currently can't be checked at compile time due to the limitation of const generics.
+    fn take_front<const K: usize, const R: usize>(
+        self,
+    ) -> ([T; K], RecursiveArrayArrayWrapper<R, T>) {
+        let (front, rest) = self.take_prefix::<K, R>();
+        (front.array, rest)
+    }
+
+    /// returns the index of the partition point of this array according to the given predicate, which is the index of the
+    /// first element for which the predicate doesn't hold. this array must be partitioned according to the predicate, such
+    /// that all elements for which it holds come before all elements for which it doesn't.
+    fn partition_point<F: FnMut(&T) -> bool>(&self, pred: F) -> usize {
+        self.as_slice().partition_point(pred)
+    }
+
+    /// returns whether the elements of this array are sorted in ascending order. returns `true` if this array has less than
+    /// 2 elements.
+    fn is_sorted(&self) -> bool
+    where
+        T: PartialOrd,
+    {
+        self.as_slice().is_sorted()
+    }
+
+    /// returns whether the elements of this array are sorted according to the given comparator, which must return whether
+    /// its two arguments are in order. returns `true` if this array has less than 2 elements.
+    fn is_sorted_by<F: FnMut(&T, &T) -> bool>(&self, compare: F) -> bool {
+        self.as_slice().is_sorted_by(compare)
+    }
+
+    /// returns the index of the first element of this array which differs from the corresponding element of the given
+    /// array, or `None` if all elements are equal.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if the length of the other array is not equal to `Self::LENGTH`.
+    fn first_difference(&self, other: &impl RecursiveArray<T>) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        check_same_length(Self::LENGTH, other.len());
+        self.as_slice()
+            .iter()
+            .zip(other.as_slice())
+            .position(|(a, b)| a != b)
+    }
+
+    /// returns the index of the last element of this array which differs from the corresponding element of the given
+    /// array, or `None` if all elements are equal.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if the length of the other array is not equal to `Self::LENGTH`.
+    fn last_difference(&self, other: &impl RecursiveArray<T>) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        check_same_length(Self::LENGTH, other.len());
+        self.as_slice()
+            .iter()
+            .zip(other.as_slice())
+            .rposition(|(a, b)| a != b)
+    }
+
+    /// lexicographically compares the elements of this array with the elements yielded by the given iterator, without
+    /// collecting them. if one of them is a prefix of the other, the shorter one is considered to be less than the other.
+    fn cmp_iter<I: IntoIterator<Item = T>>(&self, other: I) -> core::cmp::Ordering
+    where
+        T: Ord,
+    {
+        let mut other = other.into_iter();
+        for element in self.as_slice() {
+            let ordering = match other.next() {
+                Some(other_element) => element.cmp(&other_element),
+                None => core::cmp::Ordering::Greater,
+            };
+            if ordering != core::cmp::Ordering::Equal {
+                return ordering;
+            }
+        }
+        if other.next().is_some() {
+            core::cmp::Ordering::Less
+        } else {
+            core::cmp::Ordering::Equal
+        }
+    }
+
+    /// returns an array of the absolute values of the elements of this array.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if the length of the output array (`N`) is not equal to `Self::LENGTH`.
+    /// this condition currently can't be checked at compile time due to the limitation of const generics.
+    fn abs<const N: usize>(&self) -> RecursiveArrayArrayWrapper<N, T>
+    where
+        T: Signed,
+    {
+        check_output_length::<N>(Self::LENGTH);
+        let slice = self.as_slice();
+        RecursiveArrayArrayWrapper::new(core::array::from_fn(|i| T::abs(slice[i])))
+    }
+
+    /// replaces each element of this array with its absolute value, in place.
+    fn abs_mut(&mut self)
+    where
+        T: Signed,
+    {
+        for element in self.as_mut_slice() {
+            *element = T::abs(*element);
+        }
+    }
+
+    /// returns an array of the reciprocals (`1 / x`) of the elements of this array. the reciprocal of a zero element is an
+    /// infinity with the same sign as the zero.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if the length of the output array (`N`) is not equal to `Self::LENGTH`.
+    /// this condition currently can't be checked at compile time due to the limitation of const generics.
+    fn recip<const N: usize>(&self) -> RecursiveArrayArrayWrapper<N, T>
+    where
+        T: Float,
+    {
+        check_output_length::<N>(Self::LENGTH);
+        let slice = self.as_slice();
+        RecursiveArrayArrayWrapper::new(core::array::from_fn(|i| T::ONE / slice[i]))
+    }
+
+    /// replaces each element of this array with its reciprocal (`1 / x`), in place. the reciprocal of a zero element is an
+    /// infinity with the same sign as the zero.
+    fn recip_mut(&mut self)
+    where
+        T: Float,
+    {
+        for element in self.as_mut_slice() {
+            *element = T::ONE / *element;
+        }
+    }
+
+    /// copies each consecutive block of `K` elements of this array into a regular array (`[T; K]`), and collects them into
+    /// a vector. if `Self::LENGTH` is not a multiple of `K`, the trailing partial block is not included in the vector.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if `K` is `0`.
+    #[cfg(feature = "alloc")]
+    fn chunk_vec<const K: usize>(&self) -> alloc::vec::Vec<[T; K]>
+    where
+        T: Copy,
+    {
+        let chunks = self.as_slice().chunks_exact(K);
+        let mut result = alloc::vec::Vec::with_capacity(chunks.len());
+        for chunk in chunks {
+            result.push(*chunk.first_chunk().unwrap());
+        }
+        result
+    }
+
+    /// converts this array to a column matrix, which is a multiplier of `N` rows, each of which contains a single element
+    /// of this array.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if the amount of rows (`N`) is not equal to `Self::LENGTH`.
+    /// this condition currently can't be checked at compile time due to the limitation of const generics.
+    fn into_column<const N: usize>(
+        self,
+    ) -> RecursiveArrayMultiplier<N, T, RecursiveArraySingleItem<T>> {
+        RecursiveArrayMultiplier::new(self.to_array::<N>().map(RecursiveArraySingleItem::new))
+    }
+
+    /// converts this array to a row matrix, which is a multiplier of a single row, which contains all of the elements of
+    /// this array.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if the amount of columns (`N`) is not equal to `Self::LENGTH`.
+    /// this condition currently can't be checked at compile time due to the limitation of const generics.
+    fn into_row<const N: usize>(
+        self,
+    ) -> RecursiveArrayMultiplier<1, T, RecursiveArrayArrayWrapper<N, T>> {
+        RecursiveArrayMultiplier::new([RecursiveArrayArrayWrapper::new(self.to_array::<N>())])
+    }
+}
+
+/// defines trait methods which read integers of the given types from a recursive byte array.
+macro_rules! byte_array_read_methods {
+    ($($method: ident => $integer: ty, $from_bytes: ident, $endianness: literal);+ $(;)?) => {
+        $(
+            #[doc = concat!(
+                "reads a ", $endianness, " `", stringify!($integer), "` from the bytes of this array at the given byte ",
+                "offset. returns `None` if some of the bytes of the integer are out of the bounds of this array."
+            )]
+            fn $method(&self, offset: usize) -> Option<$integer> {
+                let bytes = self.as_slice().get(offset..)?.first_chunk()?;
+                Some(<$integer>::$from_bytes(*bytes))
+            }
+        )+
+    };
+}
+
+/// an extension trait for recursive arrays of bytes, which provides operations that are specific to bytes.
+///
+/// this trait is implemented for all recursive arrays of bytes.
+pub trait RecursiveByteArray: RecursiveArray<u8> {
+    /// returns the polynomial rolling hashes of all windows of `K` consecutive bytes of this array, such that the `i`-th
+    /// element of the result is the hash of the window starting at index `i`, which is useful for rabin-karp substring search.
+    ///
+    /// the hash of a window `w` is `w[0] * 257^(K - 1) + w[1] * 257^(K - 2) + ... + w[K - 1]`, modulo `1_000_000_007`.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if `K` is `0` or greater than `Self::LENGTH`, or if the length of the output array (`OUT`) is
+    /// not equal to `Self::LENGTH - K + 1`. these conditions currently can't be checked at compile time due to the limitation
+    /// of const generics.
+    fn rolling_hashes<const K: usize, const OUT: usize>(
+        &self,
+    ) -> RecursiveArrayArrayWrapper<OUT, u64> {
+        const BASE: u64 = 257;
+        const MODULUS: u64 = 1_000_000_007;
+
+        if K == 0 || K > Self::LENGTH {
+            panic!(
+                "tried to hash windows of length {} of a recursive array of length {}",
+                K,
+                Self::LENGTH,
+            );
+        }
+        check_output_length::<OUT>(Self::LENGTH - K + 1);
+        let slice = self.as_slice();
+
+        // the weight of the first byte of each window.
+        let first_weight = (1..K).fold(1, |power, _| power * BASE % MODULUS);
+        let mut hash = slice[..K]
+            .iter()
+            .fold(0, |hash, byte| (hash * BASE + u64::from(*byte)) % MODULUS);
+        RecursiveArrayArrayWrapper::new(core::array::from_fn(|i| {
+            if i > 0 {
+                let removed = u64::from(slice[i - 1]) * first_weight % MODULUS;
+                hash = ((hash + MODULUS - removed) * BASE + u64::from(slice[i + K - 1])) % MODULUS;
+            }
+            hash
+        }))
+    }
+
+    /// returns the hamming distance between this array and the given array, which is the amount of bits which differ between
+    /// them.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if the length of the other array is not equal to `Self::LENGTH`.
+    fn hamming_distance(&self, other: &impl RecursiveArray<u8>) -> u32 {
+        check_same_length(Self::LENGTH, other.len());
+        self.as_slice()
+            .iter()
+            .zip(other.as_slice())
+            .map(|(a, b)| (a ^ b).count_ones())
+            .sum()
+    }
+
+    /// lexicographically compares the bytes of this array with the bytes of the given array, which may be of a different
+    /// type. the bytes are compared as slices, which is lowered to a `memcmp`.
+    fn fast_cmp(&self, other: &impl RecursiveArray<u8>) -> core::cmp::Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+
+    byte_array_read_methods!(
+        read_u16_le => u16, from_le_bytes, "little endian";
+        read_u16_be => u16, from_be_bytes, "big endian";
+        read_u32_le => u32, from_le_bytes, "little endian";
+        read_u32_be => u32, from_be_bytes, "big endian";
+        read_u64_le => u64, from_le_bytes, "little endian";
+        read_u64_be => u64, from_be_bytes, "big endian";
+    );
+
+    /// returns whether the last byte of this array is the checksum of all of the other bytes, where the checksum is their
+    /// wrapping (modulo 256) sum. returns `false` if this array is empty.
+    fn verify_trailing_checksum(&self) -> bool {
+        self.verify_trailing_checksum_with(wrapping_byte_sum)
+    }
+
+    /// sets the last byte of this array to the checksum of all of the other bytes, where the checksum is their wrapping
+    /// (modulo 256) sum.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if this array is empty.
+    fn set_trailing_checksum(&mut self) {
+        self.set_trailing_checksum_with(wrapping_byte_sum)
+    }
+
+    /// returns whether the last byte of this array is equal to the checksum of all of the other bytes, which is computed
+    /// using the given function. returns `false` if this array is empty.
+    fn verify_trailing_checksum_with<F: FnOnce(&[u8]) -> u8>(&self, checksum: F) -> bool {
+        match self.as_slice().split_last() {
+            Some((last, rest)) => checksum(rest) == *last,
+            None => false,
+        }
+    }
+
+    /// sets the last byte of this array to the checksum of all of the other bytes, which is computed using the given
+    /// function.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if this array is empty.
+    fn set_trailing_checksum_with<F: FnOnce(&[u8]) -> u8>(&mut self, checksum: F) {
+        match self.as_mut_slice().split_last_mut() {
+            Some((last, rest)) => *last = checksum(rest),
+            None => panic!("tried to set the trailing checksum of an empty recursive array"),
+        }
+    }
+
+    /// xors each byte of this array with the corresponding byte of the given array, in place.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if the length of the other array is not equal to `Self::LENGTH`.
+    fn xor_with(&mut self, other: &impl RecursiveArray<u8>) {
+        check_same_length(Self::LENGTH, other.len());
+        for (byte, other_byte) in self.as_mut_slice().iter_mut().zip(other.as_slice()) {
+            *byte ^= *other_byte;
+        }
+    }
+
+    /// returns an array of the xor of each byte of this array with the corresponding byte of the given array.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if the length of the other array or the length of the output array (`N`) is not equal to
+    /// `Self::LENGTH`. this condition currently can't be checked at compile time due to the limitation of const generics.
+    fn xored<const N: usize>(
+        &self,
+        other: &impl RecursiveArray<u8>,
+    ) -> RecursiveArrayArrayWrapper<N, u8> {
+        zip_elements(self.as_slice(), other.as_slice(), |byte, other_byte| {
+            byte ^ other_byte
+        })
+    }
+
+    /// returns a histogram of the bytes of this array, such that the `i`-th element of the result is the amount of bytes
+    /// which are equal to `i`. bytes which are greater than or equal to `BINS` are ignored, so they are not counted in any
+    /// bin.
+    fn histogram<const BINS: usize>(&self) -> RecursiveArrayArrayWrapper<BINS, usize> {
+        let mut bins = [0; BINS];
+        for byte in self.as_slice() {
+            if let Some(bin) = bins.get_mut(usize::from(*byte)) {
+                *bin += 1;
+            }
+        }
+        RecursiveArrayArrayWrapper::new(bins)
+    }
+
+    /// returns the shannon entropy of the bytes of this array, in bits per byte, which is between `0` (for empty arrays and
+    /// arrays whose bytes are all equal) and `8` (for arrays in which all byte values appear equally often).
+    #[cfg(feature = "libm")]
+    fn entropy(&self) -> f32 {
+        if Self::LENGTH == 0 {
+            return 0.0;
+        }
+        let length = Self::LENGTH as f32;
+        self.histogram::<256>()
+            .as_slice()
+            .iter()
+            .filter(|count| **count != 0)
+            .fold(0.0, |entropy, count| {
+                let probability = *count as f32 / length;
+                entropy - probability * libm::log2f(probability)
+            })
+    }
+}
+impl<R: RecursiveArray<u8>> RecursiveByteArray for R {}
+
+/// implements the comparison and hashing traits for a recursive array type based on its contents, such that two recursive
+/// arrays are equal if they contain the same elements, even if they are of different types. the traits are implemented by
+/// comparing and hashing the elements of the arrays as slices, which keeps them all consistent with each other.
+///
+/// this also implements the comparisons of [`EmptyRecursiveArray`] with the type, since the element type of the empty
+/// array can't be inferred from a blanket implementation over all recursive arrays.
+macro_rules! impl_content_comparisons {
+    ([$($generics: tt)*], $ty: ty) => {
+        impl<$($generics)*, R: RecursiveArray<T>> PartialEq<R> for $ty
+        where
+            T: PartialEq,
+        {
+            fn eq(&self, other: &R) -> bool {
+                self.as_slice() == other.as_slice()
+            }
+        }
+        impl<$($generics)*> Eq for $ty where T: Eq {}
+        impl<$($generics)*, R: RecursiveArray<T>> PartialOrd<R> for $ty
+        where
+            T: PartialOrd,
+        {
+            fn partial_cmp(&self, other: &R) -> Option<core::cmp::Ordering> {
+                self.as_slice().partial_cmp(other.as_slice())
+            }
+        }
+        impl<$($generics)*> Ord for $ty
+        where
+            T: Ord,
+        {
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                self.as_slice().cmp(other.as_slice())
+            }
+        }
+        impl<$($generics)*> core::hash::Hash for $ty
+        where
+            T: core::hash::Hash,
+        {
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                self.as_slice().hash(state)
+            }
+        }
+        impl<$($generics)*> PartialEq<$ty> for EmptyRecursiveArray
+        where
+            T: PartialEq,
+        {
+            fn eq(&self, other: &$ty) -> bool {
+                <[T]>::eq(&[], other.as_slice())
+            }
+        }
+        impl<$($generics)*> PartialOrd<$ty> for EmptyRecursiveArray
+        where
+            T: PartialOrd,
+        {
+            fn partial_cmp(&self, other: &$ty) -> Option<core::cmp::Ordering> {
+                <[T]>::partial_cmp(&[], other.as_slice())
+            }
+        }
+    };
+}
+
+/// an empty recrusive array.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EmptyRecursiveArray;
+impl PartialEq for EmptyRecursiveArray {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+impl Eq for EmptyRecursiveArray {}
+impl PartialOrd for EmptyRecursiveArray {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for EmptyRecursiveArray {
+    fn cmp(&self, _other: &Self) -> core::cmp::Ordering {
+        core::cmp::Ordering::Equal
+    }
+}
+impl core::hash::Hash for EmptyRecursiveArray {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        // hash like an empty slice, to be consistent with the other recursive arrays, which are hashed as slices.
+        <[()]>::hash(&[], state)
+    }
+}
+impl<T> AsRef<[T]> for EmptyRecursiveArray {
+    fn as_ref(&self) -> &[T] {
+        &[]
+    }
+}
+impl<T> AsMut<[T]> for EmptyRecursiveArray {
+    fn as_mut(&mut self) -> &mut [T] {
+        &mut []
+    }
+}
+unsafe impl<T> RecursiveArray<T> for EmptyRecursiveArray {
+    const LENGTH: usize = 0;
+}
+
+/// a recursive array with a single item.
+#[derive(Debug, Clone, Default)]
+#[repr(transparent)]
+pub struct RecursiveArraySingleItem<T> {
+    item: T,
+}
+impl<T> AsRef<[T]> for RecursiveArraySingleItem<T> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+impl<T> AsMut<[T]> for RecursiveArraySingleItem<T> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+unsafe impl<T> RecursiveArray<T> for RecursiveArraySingleItem<T> {
+    const LENGTH: usize = 1;
+}
+impl_content_comparisons!([T], RecursiveArraySingleItem<T>);
+impl<T> RecursiveArraySingleItem<T> {
+    /// creates a new recrusive array with a single item.
+    pub fn new(item: T) -> Self {
+        Self { item }
+    }
+
+    /// returns a reference to the item of this array.
+    pub fn get(&self) -> &T {
+        &self.item
+    }
+
+    /// returns a mutable reference to the item of this array.
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.item
+    }
+
+    /// consumes this array, returning its item.
+    pub fn into_inner(self) -> T {
+        self.item
+    }
+}
+
+/// a recursive array which concatenates 2 recursive arrays.
+#[derive(Debug, Clone, Default)]
+#[repr(C)]
+pub struct RecursiveArrayConcatenation<T, A: RecursiveArray<T>, B: RecursiveArray<T>> {
+    a: A,
+    b: B,
+    phantom: PhantomData<T>,
+}
+impl<T, A: RecursiveArray<T>, B: RecursiveArray<T>> AsRef<[T]>
+    for RecursiveArrayConcatenation<T, A, B>
+{
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+impl<T, A: RecursiveArray<T>, B: RecursiveArray<T>> AsMut<[T]>
+    for RecursiveArrayConcatenation<T, A, B>
+{
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+unsafe impl<T, A: RecursiveArray<T>, B: RecursiveArray<T>> RecursiveArray<T>
+    for RecursiveArrayConcatenation<T, A, B>
+{
+    const LENGTH: usize = A::LENGTH + B::LENGTH;
+}
+impl_content_comparisons!(
+    [T, A: RecursiveArray<T>, B: RecursiveArray<T>],
+    RecursiveArrayConcatenation<T, A, B>
+);
+impl<T, A: RecursiveArray<T>, B: RecursiveArray<T>> RecursiveArrayConcatenation<T, A, B> {
+    /// creates a new recrusive array which concatenates the 2 given recursive arrays.
+    pub fn new(a: A, b: B) -> Self {
+        Self {
+            a,
+            b,
+            phantom: PhantomData,
+        }
+    }
+
+    /// returns a reference to the first of the 2 concatenated arrays.
+    pub fn left(&self) -> &A {
+        &self.a
+    }
+
+    /// returns a reference to the second of the 2 concatenated arrays.
+    pub fn right(&self) -> &B {
+        &self.b
+    }
+
+    /// returns a mutable reference to the first of the 2 concatenated arrays.
+    pub fn left_mut(&mut self) -> &mut A {
+        &mut self.a
+    }
+
+    /// returns a mutable reference to the second of the 2 concatenated arrays.
+    pub fn right_mut(&mut self) -> &mut B {
+        &mut self.b
+    }
+
+    /// consumes this array, returning the 2 concatenated arrays.
+    pub fn into_halves(self) -> (A, B) {
+        (self.a, self.b)
+    }
+}
+impl<T, A: RecursiveArray<T>> RecursiveArrayConcatenation<T, A, RecursiveArraySingleItem<T>> {
+    /// pops an element from the back of this array.
+    /// returns a tuple whose first element is the popped item and its second element is the rest of the array.
+    pub fn pop_back(self) -> (T, A) {
+        (self.b.item, self.a)
+    }
+}
+
+impl<T, B: RecursiveArray<T>> RecursiveArrayConcatenation<T, RecursiveArraySingleItem<T>, B> {
+    /// pops an element from the front of this array.
+    /// returns a tuple whose first element is the popped item and its second element is the rest of the array.
+    pub fn pop_front(self) -> (T, B) {
+        (self.a.item, self.b)
+    }
+}
+
+/// a recursive array wrapper which wraps a regular rust array (`[T; N]`) and allows it to be treated as a recursive array.
+#[derive(Debug, Clone)]
+#[repr(transparent)]
+pub struct RecursiveArrayArrayWrapper<const N: usize, T> {
+    array: [T; N],
+}
+impl<const N: usize, T> RecursiveArrayArrayWrapper<N, T> {
+    /// creates a new recursive array wrapper which wraps the given array.
+    pub fn new(array: [T; N]) -> Self {
+        Self { array }
+    }
+
+    /// creates a new recursive array wrapper whose elements are generated by calling the given function `N` times, in order,
+    /// with a mutable reference to the given state, which can carry information between the calls.
+    pub fn unfold<S, F: FnMut(&mut S) -> T>(mut seed: S, mut f: F) -> Self {
+        Self::new(core::array::from_fn(|_| f(&mut seed)))
+    }
+
+    /// returns the outer product of this vector and the given vector, which is a matrix of `N` rows and `M` columns, where
+    /// the element at row `i` and column `j` is `self[i] * other[j]`.
+    pub fn outer<const M: usize>(
+        &self,
+        other: &RecursiveArrayArrayWrapper<M, T>,
+    ) -> RecursiveArrayMultiplier<N, T, RecursiveArrayArrayWrapper<M, T>>
+    where
+        T: Mul<Output = T> + Copy,
+    {
+        RecursiveArrayMultiplier::new(core::array::from_fn(|i| {
+            RecursiveArrayArrayWrapper::new(core::array::from_fn(|j| {
+                self.array[i] * other.array[j]
+            }))
+        }))
+    }
+}
+impl<const N: usize, T> AsRef<[T]> for RecursiveArrayArrayWrapper<N, T> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+impl<const N: usize, T> AsMut<[T]> for RecursiveArrayArrayWrapper<N, T> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+unsafe impl<const N: usize, T> RecursiveArray<T> for RecursiveArrayArrayWrapper<N, T> {
+    const LENGTH: usize = N;
+}
+impl_content_comparisons!([const N: usize, T], RecursiveArrayArrayWrapper<N, T>);
+impl<const N: usize, T: Integer> RecursiveArrayArrayWrapper<N, T> {
+    /// converts the given floats to fixed-point numbers with `FRAC` fractional bits (Q-format), which is each float
+    /// multiplied by `2^FRAC`. this is the reverse of [`to_float_q`](RecursiveArray::to_float_q).
+    ///
+    /// the scaled values are rounded towards zero. values which are out of the range of `T` saturate at its numeric bounds,
+    /// and NaN is converted to `0`.
+    ///
+    /// # Panics
+    ///
+    /// this function panics if the length of the given array is not equal to `N`.
+    pub fn from_float_q<const FRAC: usize>(values: &impl RecursiveArray<f32>) -> Self {
+        check_same_length(N, values.len());
+        let scale = fixed_point_scale(FRAC);
+        let slice = values.as_slice();
+        Self::new(core::array::from_fn(|i| T::from_f32(slice[i] * scale)))
+    }
+}
+impl<const N: usize, T> RecursiveArrayArrayWrapper<N, core::mem::MaybeUninit<T>> {
+    /// creates a new recursive array wrapper whose elements are all uninitialized.
+    pub fn uninit() -> Self {
+        Self {
+            array: [const { core::mem::MaybeUninit::uninit() }; N],
+        }
+    }
+
+    /// converts this array of possibly uninitialized elements to an array of initialized elements.
+    ///
+    /// # Safety
+    ///
+    /// the caller must make sure that all elements of this array are initialized. note that if this array is dropped while
+    /// only some of its elements are initialized, those elements are not dropped, so it is the responsibility of the caller
+    /// to drop them.
+    pub unsafe fn assume_init(self) -> RecursiveArrayArrayWrapper<N, T> {
+        unsafe { runtime_checked_transmute(self) }
+    }
+}
+
+/// implements conversions between recursive array wrappers and tuples of the same length.
+macro_rules! impl_tuple_conversions {
+    (@element_type $element: ident) => {
+        T
+    };
+    ($($length: literal => ($($element: ident),+)),+ $(,)?) => {
+        $(
+            impl<T> From<($(impl_tuple_conversions!(@element_type $element),)+)>
+                for RecursiveArrayArrayWrapper<$length, T>
+            {
+                fn from(($($element,)+): ($(impl_tuple_conversions!(@element_type $element),)+)) -> Self {
+                    Self {
+                        array: [$($element),+],
+                    }
+                }
+            }
+            impl<T> From<RecursiveArrayArrayWrapper<$length, T>>
+                for ($(impl_tuple_conversions!(@element_type $element),)+)
+            {
+                fn from(wrapper: RecursiveArrayArrayWrapper<$length, T>) -> Self {
+                    let [$($element),+] = wrapper.array;
+                    ($($element,)+)
+                }
+            }
+        )+
+    };
+}
+impl_tuple_conversions!(
+    2 => (a, b),
+    3 => (a, b, c),
+    4 => (a, b, c, d),
+    5 => (a, b, c, d, e),
+    6 => (a, b, c, d, e, f),
+    7 => (a, b, c, d, e, f, g),
+    8 => (a, b, c, d, e, f, g, h),
+    9 => (a, b, c, d, e, f, g, h, i),
+    10 => (a, b, c, d, e, f, g, h, i, j),
+    11 => (a, b, c, d, e, f, g, h, i, j, k),
+    12 => (a, b, c, d, e, f, g, h, i, j, k, l),
+);
+
+/// implements the bitwise operators for recursive array wrappers of the given integer types, operating element-wise.
+macro_rules! impl_bitwise_operators {
+    (@binary $integer: ty, $trait: ident, $method: ident, $assign_trait: ident, $assign_method: ident, $assign_op: tt) => {
+        impl<const N: usize> $trait for RecursiveArrayArrayWrapper<N, $integer> {
+            type Output = Self;
+
+            fn $method(mut self, rhs: Self) -> Self {
+                self.$assign_method(rhs);
+                self
+            }
+        }
+        impl<const N: usize> $assign_trait for RecursiveArrayArrayWrapper<N, $integer> {
+            fn $assign_method(&mut self, rhs: Self) {
+                for (element, rhs_element) in self.array.iter_mut().zip(rhs.array) {
+                    *element $assign_op rhs_element;
+                }
+            }
+        }
+    };
+    ($($integer: ty),+ $(,)?) => {
+        $(
+            impl_bitwise_operators!(@binary $integer, BitAnd, bitand, BitAndAssign, bitand_assign, &=);
+            impl_bitwise_operators!(@binary $integer, BitOr, bitor, BitOrAssign, bitor_assign, |=);
+            impl_bitwise_operators!(@binary $integer, BitXor, bitxor, BitXorAssign, bitxor_assign, ^=);
+            impl<const N: usize> Not for RecursiveArrayArrayWrapper<N, $integer> {
+                type Output = Self;
+
+                fn not(mut self) -> Self {
+                    for element in &mut self.array {
+                        *element = !*element;
+                    }
+                    self
+                }
+            }
+        )+
+    };
+}
+impl_bitwise_operators!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+#[cfg(feature = "bytemuck")]
+impl<const N: usize, T: bytemuck::Pod> RecursiveArrayArrayWrapper<N, T> {
+    /// casts the given bytes to a recursive array reference. this is a zero cost operation, which just casts the bytes.
+    ///
+    /// returns an error if the length of the bytes is not equal to `N * size_of::<T>()`, or if the bytes are not aligned
+    /// to `align_of::<T>()`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, CastError> {
+        Self::check_bytes(bytes)?;
+        if core::mem::size_of::<Self>() == 0 {
+            return Ok(unsafe { &*core::ptr::NonNull::dangling().as_ptr() });
+        }
+        Ok(unsafe { &*bytes.as_ptr().cast() })
+    }
+
+    /// casts the given mutable bytes to a recursive array mutable reference. this is a zero cost operation, which just
+    /// casts the bytes.
+    ///
+    /// returns an error if the length of the bytes is not equal to `N * size_of::<T>()`, or if the bytes are not aligned
+    /// to `align_of::<T>()`.
+    pub fn from_bytes_mut(bytes: &mut [u8]) -> Result<&mut Self, CastError> {
+        Self::check_bytes(bytes)?;
+        if core::mem::size_of::<Self>() == 0 {
+            return Ok(unsafe { &mut *core::ptr::NonNull::dangling().as_ptr() });
+        }
+        Ok(unsafe { &mut *bytes.as_mut_ptr().cast() })
+    }
+
+    /// reads a recursive array from the given bytes. the bytes don't need to be aligned, since they are copied.
+    ///
+    /// returns an error if the length of the bytes is not equal to `N * size_of::<T>()`.
+    pub fn from_bytes_owned(bytes: &[u8]) -> Result<Self, CastError> {
+        check_bytes_length(bytes, core::mem::size_of::<Self>())?;
+        Ok(unsafe { core::ptr::read_unaligned(bytes.as_ptr().cast()) })
+    }
+
+    /// checks that the given bytes can be casted to a reference to this type.
+    fn check_bytes(bytes: &[u8]) -> Result<(), CastError> {
+        check_bytes_length(bytes, core::mem::size_of::<Self>())?;
+        if core::mem::size_of::<Self>() != 0 && !bytes.as_ptr().cast::<T>().is_aligned() {
+            return Err(CastError::Misaligned {
+                required_alignment: core::mem::align_of::<T>(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// an error which occurs when casting bytes to a recursive array.
+#[cfg(feature = "bytemuck")]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum CastError {
+    /// the length of the bytes is not equal to the size of the recursive array.
+    LengthMismatch {
+        /// the size of the recursive array, in bytes.
+        expected: usize,
+        /// the length of the bytes.
+        actual: usize,
+    },
+    /// the bytes are not properly aligned for the element type of the recursive array.
+    Misaligned {
+        /// the alignment required by the element type of the recursive array.
+        required_alignment: usize,
+    },
+}
+#[cfg(feature = "bytemuck")]
+impl core::fmt::Display for CastError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            CastError::LengthMismatch { expected, actual } => write!(
+                f,
+                "tried to cast {} bytes to a recursive array of {} bytes",
+                actual, expected
+            ),
+            CastError::Misaligned { required_alignment } => write!(
+                f,
+                "tried to cast bytes which are not aligned to {} bytes to a recursive array",
+                required_alignment
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl<const N: usize, T> From<RecursiveArrayArrayWrapper<N, T>> for nalgebra::SVector<T, N> {
+    fn from(wrapper: RecursiveArrayArrayWrapper<N, T>) -> Self {
+        nalgebra::SVector::from_array_storage(nalgebra::ArrayStorage([wrapper.array]))
+    }
+}
+#[cfg(feature = "nalgebra")]
+impl<const N: usize, T> From<nalgebra::SVector<T, N>> for RecursiveArrayArrayWrapper<N, T> {
+    fn from(vector: nalgebra::SVector<T, N>) -> Self {
+        let nalgebra::ArrayStorage([array]) = vector.data;
+        Self { array }
+    }
+}
+
+/// a helper for incrementally filling a recursive byte array from successive chunks of bytes, for example when receiving
+/// the bytes from a nonblocking source.
+#[derive(Debug)]
+pub struct RecursiveArrayFiller<'a, const N: usize> {
+    array: &'a mut RecursiveArrayArrayWrapper<N, u8>,
+    filled: usize,
+}
+impl<'a, const N: usize> RecursiveArrayFiller<'a, N> {
+    /// creates a new filler which fills the given array, starting from its first byte.
+    pub fn new(array: &'a mut RecursiveArrayArrayWrapper<N, u8>) -> Self {
+        Self { array, filled: 0 }
+    }
+
+    /// copies as many bytes as possible from the given chunk into the unfilled part of the array.
+    /// returns the amount of bytes consumed from the chunk, which is less than the length of the chunk if the chunk contains
+    /// more bytes than what is left to fill.
+    pub fn feed(&mut self, bytes: &[u8]) -> usize {
+        let consumed = core::cmp::min(bytes.len(), self.remaining());
+        self.array.array[self.filled..self.filled + consumed].copy_from_slice(&bytes[..consumed]);
+        self.filled += consumed;
+        consumed
+    }
+
+    /// returns the amount of bytes which are still required to fill the array.
+    pub fn remaining(&self) -> usize {
+        N - self.filled
+    }
+
+    /// returns whether the array was completely filled.
+    pub fn is_full(&self) -> bool {
+        self.filled == N
+    }
+
+    /// finishes filling the array, returning an error if it was not completely filled.
+    pub fn finish(self) -> Result<(), Incomplete> {
+        if self.is_full() {
+            Ok(())
+        } else {
+            Err(Incomplete {
+                missing: self.remaining(),
+            })
+        }
+    }
+}
+
+/// an error which indicates that a recursive array was not completely filled.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct Incomplete {
+    /// the amount of bytes which were missing to fill the array.
+    pub missing: usize,
+}
+impl core::fmt::Display for Incomplete {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "recursive array is incomplete, {} bytes are missing",
+            self.missing
+        )
+    }
+}
+
+/// a recursive array which multiplies the given inner recursive array type `N` times.
+#[derive(Debug, Clone)]
+#[repr(transparent)]
+pub struct RecursiveArrayMultiplier<const N: usize, T, A: RecursiveArray<T>> {
     multiplied: [A; N],
     phantom: PhantomData<T>,
 }
-impl<const N: usize, T, A: RecursiveArray<T>> RecursiveArrayMultiplier<N, T, A> {
-    /// creates a new recursive array multiplier with the given values.
-    pub fn new(values: [A; N]) -> Self {
+impl<const N: usize, T, A: RecursiveArray<T>> RecursiveArrayMultiplier<N, T, A> {
+    /// creates a new recursive array multiplier with the given values.
+    pub fn new(values: [A; N]) -> Self {
+        Self {
+            multiplied: values,
+            phantom: PhantomData,
+        }
+    }
+
+    /// returns an iterator over the `N` sub-arrays of this multiplier.
+    pub fn iter_sub(&self) -> core::slice::Iter<'_, A> {
+        self.multiplied.iter()
+    }
+
+    /// returns an iterator over mutable references to the `N` sub-arrays of this multiplier.
+    pub fn iter_sub_mut(&mut self) -> core::slice::IterMut<'_, A> {
+        self.multiplied.iter_mut()
+    }
+
+    /// returns a 2-dimensional `ndarray` view of this multiplier, without copying it. the view has `N` rows, each of which is
+    /// one of the sub-arrays of this multiplier, and `A::LENGTH` columns.
+    #[cfg(feature = "ndarray")]
+    pub fn as_ndarray_2d(&self) -> ndarray::ArrayView2<'_, T> {
+        ndarray::ArrayView2::from_shape((N, A::LENGTH), self.as_slice())
+            .expect("the shape of a multiplier always matches its length")
+    }
+
+    /// returns a mutable 2-dimensional `ndarray` view of this multiplier, without copying it. the view has `N` rows, each of
+    /// which is one of the sub-arrays of this multiplier, and `A::LENGTH` columns.
+    #[cfg(feature = "ndarray")]
+    pub fn as_ndarray_2d_mut(&mut self) -> ndarray::ArrayViewMut2<'_, T> {
+        ndarray::ArrayViewMut2::from_shape((N, A::LENGTH), self.as_mut_slice())
+            .expect("the shape of a multiplier always matches its length")
+    }
+
+    /// folds the `N` sub-arrays of this multiplier into a single value, by repeatedly applying the given function to the
+    /// accumulated value and each sub-array, in order.
+    pub fn fold_rows<B, F: FnMut(B, &A) -> B>(&self, init: B, f: F) -> B {
+        self.multiplied.iter().fold(init, f)
+    }
+
+    /// cyclically rotates the `N` sub-arrays of this multiplier to the left by `k` positions, such that the sub-array at index
+    /// `k % N` becomes the first one. the elements within each sub-array are left intact.
+    pub fn rotate_rows(&mut self, k: usize) {
+        if N != 0 {
+            self.multiplied.rotate_left(k % N);
+        }
+    }
+
+    /// consumes this multiplier, returning its `N` sub-arrays.
+    pub fn into_chunks(self) -> [A; N] {
+        self.multiplied
+    }
+}
+impl<const N: usize, T> RecursiveArrayMultiplier<N, T, RecursiveArrayArrayWrapper<N, T>> {
+    /// creates a new square matrix multiplier whose diagonal is the given array, where the element at index `i` of the array
+    /// is placed at row `i` and column `i`, and all other elements are `T::default()`.
+    pub fn from_diagonal(diag: RecursiveArrayArrayWrapper<N, T>) -> Self
+    where
+        T: Default + Copy,
+    {
+        Self::new(core::array::from_fn(|row| {
+            RecursiveArrayArrayWrapper::new(core::array::from_fn(|column| {
+                if row == column {
+                    diag.array[row]
+                } else {
+                    T::default()
+                }
+            }))
+        }))
+    }
+
+    /// returns the trace of this square matrix, which is the sum of the elements on its diagonal. returns `T::default()` if
+    /// `N` is `0`.
+    pub fn trace(&self) -> T
+    where
+        T: Add<Output = T> + Copy + Default,
+    {
+        let mut sum = T::default();
+        for (i, row) in self.multiplied.iter().enumerate() {
+            sum = sum + row.array[i];
+        }
+        sum
+    }
+}
+impl<const N: usize, T, A: RecursiveArray<T>> AsRef<[T]> for RecursiveArrayMultiplier<N, T, A> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+impl<const N: usize, T, A: RecursiveArray<T>> AsMut<[T]> for RecursiveArrayMultiplier<N, T, A> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+unsafe impl<const N: usize, T, A: RecursiveArray<T>> RecursiveArray<T>
+    for RecursiveArrayMultiplier<N, T, A>
+{
+    const LENGTH: usize = A::LENGTH * N;
+}
+impl_content_comparisons!(
+    [const N: usize, T, A: RecursiveArray<T>],
+    RecursiveArrayMultiplier<N, T, A>
+);
+
+/// an iterator which moves the elements out of a recursive array.
+pub struct RecursiveArrayIntoIter<T, A: RecursiveArray<T>> {
+    array: core::mem::ManuallyDrop<A>,
+    /// the range of indexes of the elements which were not yet moved out of the array.
+    alive: core::ops::Range<usize>,
+    phantom: PhantomData<T>,
+}
+impl<T, A: RecursiveArray<T>> RecursiveArrayIntoIter<T, A> {
+    /// creates a new iterator which moves the elements out of the given recursive array.
+    pub fn new(array: A) -> Self {
         Self {
-            multiplied: values,
+            array: core::mem::ManuallyDrop::new(array),
+            alive: 0..A::LENGTH,
             phantom: PhantomData,
         }
     }
+
+    /// returns the elements which were not yet yielded by this iterator as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        &self.array.as_slice()[self.alive.clone()]
+    }
+
+    /// returns the elements which were not yet yielded by this iterator as a mutable slice.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.array.as_mut_slice()[self.alive.clone()]
+    }
 }
-impl<const N: usize, T, A: RecursiveArray<T>> AsRef<[T]> for RecursiveArrayMultiplier<N, T, A> {
-    fn as_ref(&self) -> &[T] {
-        self.as_slice()
+impl<T, A: RecursiveArray<T>> Iterator for RecursiveArrayIntoIter<T, A> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let index = self.alive.next()?;
+        // the element at this index is no longer alive, so it will not be read or dropped again.
+        Some(unsafe { core::ptr::read(self.array.as_slice().as_ptr().add(index)) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.alive.size_hint()
     }
 }
-impl<const N: usize, T, A: RecursiveArray<T>> AsMut<[T]> for RecursiveArrayMultiplier<N, T, A> {
-    fn as_mut(&mut self) -> &mut [T] {
-        self.as_mut_slice()
+impl<T, A: RecursiveArray<T>> DoubleEndedIterator for RecursiveArrayIntoIter<T, A> {
+    fn next_back(&mut self) -> Option<T> {
+        let index = self.alive.next_back()?;
+        // the element at this index is no longer alive, so it will not be read or dropped again.
+        Some(unsafe { core::ptr::read(self.array.as_slice().as_ptr().add(index)) })
+    }
+}
+impl<T, A: RecursiveArray<T>> ExactSizeIterator for RecursiveArrayIntoIter<T, A> {}
+impl<T, A: RecursiveArray<T>> core::iter::FusedIterator for RecursiveArrayIntoIter<T, A> {}
+impl<T, A: RecursiveArray<T>> Drop for RecursiveArrayIntoIter<T, A> {
+    fn drop(&mut self) {
+        unsafe { core::ptr::drop_in_place(self.as_mut_slice()) }
+    }
+}
+impl<T: core::fmt::Debug, A: RecursiveArray<T>> core::fmt::Debug for RecursiveArrayIntoIter<T, A> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("RecursiveArrayIntoIter")
+            .field(&self.as_slice())
+            .finish()
+    }
+}
+
+impl<T> IntoIterator for RecursiveArraySingleItem<T> {
+    type Item = T;
+    type IntoIter = RecursiveArrayIntoIter<T, Self>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        RecursiveArrayIntoIter::new(self)
+    }
+}
+impl<T, A: RecursiveArray<T>, B: RecursiveArray<T>> IntoIterator
+    for RecursiveArrayConcatenation<T, A, B>
+{
+    type Item = T;
+    type IntoIter = RecursiveArrayIntoIter<T, Self>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        RecursiveArrayIntoIter::new(self)
+    }
+}
+impl<const N: usize, T> IntoIterator for RecursiveArrayArrayWrapper<N, T> {
+    type Item = T;
+    type IntoIter = RecursiveArrayIntoIter<T, Self>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        RecursiveArrayIntoIter::new(self)
+    }
+}
+impl<const N: usize, T, A: RecursiveArray<T>> IntoIterator for RecursiveArrayMultiplier<N, T, A> {
+    type Item = T;
+    type IntoIter = RecursiveArrayIntoIter<T, Self>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        RecursiveArrayIntoIter::new(self)
+    }
+}
+
+/// implements `serde`'s [`Serialize`](serde::Serialize) and [`Deserialize`](serde::Deserialize) traits for a recursive array
+/// type, which is (de)serialized as a tuple of its `LENGTH` elements, regardless of its internal structure.
+#[cfg(feature = "serde")]
+macro_rules! impl_serde {
+    ([$($generics: tt)*], $ty: ty) => {
+        impl<$($generics)*> serde::Serialize for $ty
+        where
+            T: serde::Serialize,
+        {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serialize_elements(self.as_slice(), serializer)
+            }
+        }
+        impl<'de, $($generics)*> serde::Deserialize<'de> for $ty
+        where
+            T: serde::Deserialize<'de>,
+        {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserialize_elements::<D, T, Self>(deserializer)
+            }
+        }
+    };
+}
+#[cfg(feature = "serde")]
+impl_serde!([T], RecursiveArraySingleItem<T>);
+#[cfg(feature = "serde")]
+impl_serde!(
+    [T, A: RecursiveArray<T>, B: RecursiveArray<T>],
+    RecursiveArrayConcatenation<T, A, B>
+);
+#[cfg(feature = "serde")]
+impl_serde!([const N: usize, T], RecursiveArrayArrayWrapper<N, T>);
+#[cfg(feature = "serde")]
+impl_serde!(
+    [const N: usize, T, A: RecursiveArray<T>],
+    RecursiveArrayMultiplier<N, T, A>
+);
+#[cfg(feature = "serde")]
+impl serde::Serialize for EmptyRecursiveArray {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_elements::<S, u8>(&[], serializer)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for EmptyRecursiveArray {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_elements::<D, serde::de::IgnoredAny, Self>(deserializer)
+    }
+}
+
+/// serializes the given elements as a tuple.
+#[cfg(feature = "serde")]
+fn serialize_elements<S: serde::Serializer, T: serde::Serialize>(
+    elements: &[T],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    use serde::ser::SerializeTuple;
+
+    let mut tuple = serializer.serialize_tuple(elements.len())?;
+    for element in elements {
+        tuple.serialize_element(element)?;
+    }
+    tuple.end()
+}
+
+/// deserializes a tuple of exactly `R::LENGTH` elements directly into the memory of the recursive array `R`, which fills
+/// all of its sub-arrays, since its memory is just a sequence of its elements.
+#[cfg(feature = "serde")]
+fn deserialize_elements<'de, D, T, R>(deserializer: D) -> Result<R, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: serde::Deserialize<'de>,
+    R: RecursiveArray<T>,
+{
+    /// a recursive array whose first `initialized` elements are initialized, which drops them when it is dropped.
+    struct PartialArray<T, R: RecursiveArray<T>> {
+        array: core::mem::MaybeUninit<R>,
+        initialized: usize,
+        phantom: PhantomData<T>,
+    }
+    impl<T, R: RecursiveArray<T>> Drop for PartialArray<T, R> {
+        fn drop(&mut self) {
+            if self.initialized != 0 {
+                let elements = self.array.as_mut_ptr().cast::<T>();
+                unsafe {
+                    core::ptr::drop_in_place(core::ptr::slice_from_raw_parts_mut(
+                        elements,
+                        self.initialized,
+                    ))
+                }
+            }
+        }
+    }
+
+    struct ElementsVisitor<T, R>(PhantomData<(T, R)>);
+    impl<'de, T: serde::Deserialize<'de>, R: RecursiveArray<T>> serde::de::Visitor<'de>
+        for ElementsVisitor<T, R>
+    {
+        type Value = R;
+
+        fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            write!(f, "a recursive array of length {}", R::LENGTH)
+        }
+
+        fn visit_seq<S: serde::de::SeqAccess<'de>>(self, mut seq: S) -> Result<R, S::Error> {
+            let mut array = PartialArray::<T, R> {
+                array: core::mem::MaybeUninit::uninit(),
+                initialized: 0,
+                phantom: PhantomData,
+            };
+            while array.initialized < R::LENGTH {
+                let Some(element) = seq.next_element()? else {
+                    return Err(serde::de::Error::invalid_length(array.initialized, &self));
+                };
+                unsafe {
+                    array
+                        .array
+                        .as_mut_ptr()
+                        .cast::<T>()
+                        .add(array.initialized)
+                        .write(element)
+                };
+                array.initialized += 1;
+            }
+            // not all formats reject trailing elements by themselves, so they are rejected here.
+            if seq.next_element::<serde::de::IgnoredAny>()?.is_some() {
+                return Err(serde::de::Error::invalid_length(R::LENGTH + 1, &self));
+            }
+            // all elements are now initialized, so ownership of them is moved out of the partial array.
+            let array = core::mem::ManuallyDrop::new(array);
+            Ok(unsafe { array.array.assume_init_read() })
+        }
+    }
+
+    deserializer.deserialize_tuple(R::LENGTH, ElementsVisitor(PhantomData))
+}
+
+/// generates recursive array wrappers of exactly `N` elements, each of which is generated by the arbitrary strategy of `T`.
+#[cfg(feature = "proptest")]
+impl<const N: usize, T: proptest::arbitrary::Arbitrary> proptest::arbitrary::Arbitrary
+    for RecursiveArrayArrayWrapper<N, T>
+{
+    type Parameters = T::Parameters;
+    type Strategy = proptest::strategy::Map<
+        proptest::array::UniformArrayStrategy<T::Strategy, [T; N]>,
+        fn([T; N]) -> Self,
+    >;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        proptest::strategy::Strategy::prop_map(
+            proptest::array::uniform(T::arbitrary_with(args)),
+            Self::new,
+        )
+    }
+}
+
+/// generates recursive array multipliers of exactly `N` sub-arrays, each of which is generated by the arbitrary strategy of
+/// `A`.
+#[cfg(feature = "proptest")]
+impl<
+        const N: usize,
+        T: core::fmt::Debug,
+        A: RecursiveArray<T> + proptest::arbitrary::Arbitrary,
+    > proptest::arbitrary::Arbitrary for RecursiveArrayMultiplier<N, T, A>
+{
+    type Parameters = A::Parameters;
+    type Strategy = proptest::strategy::Map<
+        proptest::array::UniformArrayStrategy<A::Strategy, [A; N]>,
+        fn([A; N]) -> Self,
+    >;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        proptest::strategy::Strategy::prop_map(
+            proptest::array::uniform(A::arbitrary_with(args)),
+            Self::new,
+        )
+    }
+}
+
+/// builds recursive array wrappers of exactly `N` elements, each of which is built by the [`Arbitrary`](arbitrary::Arbitrary)
+/// implementation of `T`. returns [`NotEnoughData`](arbitrary::Error::NotEnoughData) if the data is shorter than the minimum
+/// amount of bytes required to build `N` elements.
+#[cfg(feature = "arbitrary")]
+impl<'a, const N: usize, T: arbitrary::Arbitrary<'a>> arbitrary::Arbitrary<'a>
+    for RecursiveArrayArrayWrapper<N, T>
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        check_arbitrary_data_length::<[T; N]>(u)?;
+        <[T; N]>::arbitrary(u).map(Self::new)
+    }
+
+    fn arbitrary_take_rest(u: arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        check_arbitrary_data_length::<[T; N]>(&u)?;
+        <[T; N]>::arbitrary_take_rest(u).map(Self::new)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <[T; N]>::size_hint(depth)
+    }
+
+    fn try_size_hint(
+        depth: usize,
+    ) -> Result<(usize, Option<usize>), arbitrary::MaxRecursionReached> {
+        <[T; N]>::try_size_hint(depth)
+    }
+}
+
+/// a floating point type, which can be used as the element type of floating point recursive array operations.
+///
+/// this trait is sealed, and is only implemented for `f32` and `f64`.
+pub trait Float:
+    sealed::Sealed
+    + Copy
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+{
+    /// the value `0`.
+    const ZERO: Self;
+
+    /// the value `1`.
+    const ONE: Self;
+
+    /// returns the absolute value of this value.
+    fn abs(self) -> Self;
+
+    /// converts the given integer to this type, rounding to the nearest representable value.
+    fn from_usize(value: usize) -> Self;
+
+    /// returns the square root of this value.
+    #[cfg(feature = "libm")]
+    fn sqrt(self) -> Self;
+}
+impl Float for f32 {
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+
+    fn abs(self) -> Self {
+        f32::abs(self)
+    }
+
+    fn from_usize(value: usize) -> Self {
+        value as f32
+    }
+
+    #[cfg(feature = "libm")]
+    fn sqrt(self) -> Self {
+        libm::sqrtf(self)
+    }
+}
+impl Float for f64 {
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+
+    fn abs(self) -> Self {
+        f64::abs(self)
+    }
+
+    fn from_usize(value: usize) -> Self {
+        value as f64
+    }
+
+    #[cfg(feature = "libm")]
+    fn sqrt(self) -> Self {
+        libm::sqrt(self)
+    }
+}
+
+/// a primitive integer type, which can be used as the element type of integer recursive array operations.
+///
+/// this trait is sealed, and is only implemented for the primitive integer types.
+pub trait Integer: sealed::Sealed + Copy + Ord {
+    /// the value `0`.
+    const ZERO: Self;
+
+    /// checked addition, which returns `None` if an overflow occurs.
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    /// wrapping (modular) addition.
+    fn wrapping_add(self, rhs: Self) -> Self;
+
+    /// wrapping (modular) subtraction.
+    fn wrapping_sub(self, rhs: Self) -> Self;
+
+    /// wrapping (modular) multiplication.
+    fn wrapping_mul(self, rhs: Self) -> Self;
+
+    /// saturating addition, which saturates at the numeric bounds instead of overflowing.
+    fn saturating_add(self, rhs: Self) -> Self;
+
+    /// saturating subtraction, which saturates at the numeric bounds instead of overflowing.
+    fn saturating_sub(self, rhs: Self) -> Self;
+
+    /// saturating multiplication, which saturates at the numeric bounds instead of overflowing.
+    fn saturating_mul(self, rhs: Self) -> Self;
+
+    /// returns the amount of ones in the binary representation of this value.
+    fn count_ones(self) -> u32;
+
+    /// converts this value to the nearest `f32` value.
+    fn to_f32(self) -> f32;
+
+    /// converts the given `f32` value to this type, rounding towards zero. values which are out of the range of this type
+    /// saturate at its numeric bounds, and NaN is converted to `0`.
+    fn from_f32(value: f32) -> Self;
+
+    /// reverses the order of the bits of this value.
+    fn reverse_bits(self) -> Self;
+
+    /// reverses the order of the bytes of this value.
+    fn swap_bytes(self) -> Self;
+}
+
+/// implements the [`Integer`] trait for the given primitive integer types.
+macro_rules! impl_integer {
+    ($($integer: ty),+ $(,)?) => {
+        $(
+            impl sealed::Sealed for $integer {}
+            impl Integer for $integer {
+                const ZERO: Self = 0;
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$integer>::checked_add(self, rhs)
+                }
+
+                fn wrapping_add(self, rhs: Self) -> Self {
+                    <$integer>::wrapping_add(self, rhs)
+                }
+
+                fn wrapping_sub(self, rhs: Self) -> Self {
+                    <$integer>::wrapping_sub(self, rhs)
+                }
+
+                fn wrapping_mul(self, rhs: Self) -> Self {
+                    <$integer>::wrapping_mul(self, rhs)
+                }
+
+                fn saturating_add(self, rhs: Self) -> Self {
+                    <$integer>::saturating_add(self, rhs)
+                }
+
+                fn saturating_sub(self, rhs: Self) -> Self {
+                    <$integer>::saturating_sub(self, rhs)
+                }
+
+                fn saturating_mul(self, rhs: Self) -> Self {
+                    <$integer>::saturating_mul(self, rhs)
+                }
+
+                fn count_ones(self) -> u32 {
+                    <$integer>::count_ones(self)
+                }
+
+                fn to_f32(self) -> f32 {
+                    self as f32
+                }
+
+                fn from_f32(value: f32) -> Self {
+                    value as $integer
+                }
+
+                fn reverse_bits(self) -> Self {
+                    <$integer>::reverse_bits(self)
+                }
+
+                fn swap_bytes(self) -> Self {
+                    <$integer>::swap_bytes(self)
+                }
+            }
+        )+
+    };
+}
+impl_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// a signed numeric type which has an absolute value, which is one of the primitive signed integer types or one of
+/// the primitive float types.
+pub trait Signed: sealed::Sealed + Copy {
+    /// returns the absolute value of this value.
+    ///
+    /// for integers, the absolute value of the minimum value wraps around to the minimum value itself.
+    fn abs(self) -> Self;
+}
+
+/// implements the [`Signed`] trait for the given primitive signed integer types.
+macro_rules! impl_signed_integer {
+    ($($integer: ty),+ $(,)?) => {
+        $(
+            impl Signed for $integer {
+                fn abs(self) -> Self {
+                    <$integer>::wrapping_abs(self)
+                }
+            }
+        )+
+    };
+}
+impl_signed_integer!(i8, i16, i32, i64, i128, isize);
+impl Signed for f32 {
+    fn abs(self) -> Self {
+        f32::abs(self)
+    }
+}
+impl Signed for f64 {
+    fn abs(self) -> Self {
+        f64::abs(self)
+    }
+}
+
+mod sealed {
+    /// a trait which prevents the traits of this crate which extend it from being implemented outside of this crate.
+    pub trait Sealed {}
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+}
+
+/// a macro for instantiating a recursive array with the given elements.
+///
+/// an existing recursive array can be spliced into the elements using the `..array` spread syntax, for example
+/// `recursive_array![1, 2, ..other_array, 5]`. this means that a range expression like `..end` can't be used directly as
+/// an element, so it should be bound to a variable first.
+#[macro_export]
+macro_rules! recursive_array {
+    [] => {
+        ::recursive_array::EmptyRecursiveArray
+    };
+    [.. $array: expr $(,)?] => {
+        $array
+    };
+    [.. $first_array: expr, $($rest: tt)+] => {
+        ::recursive_array::RecursiveArrayConcatenation::new(
+            $first_array,
+            ::recursive_array::recursive_array![$($rest)+],
+        )
+    };
+    [$item: expr $(,)?] => {
+        ::recursive_array::RecursiveArraySingleItem::new($item)
+    };
+    [$first_item: expr, $($rest: tt)+] => {
+        ::recursive_array::RecursiveArrayConcatenation::new(
+            ::recursive_array::RecursiveArraySingleItem::new($first_item),
+            ::recursive_array::recursive_array![$($rest)+],
+        )
+    };
+}
+
+/// a macro for concatenating the given recursive arrays, from left to right.
+///
+/// for example, `recursive_array_concat![a, b, c]` is equivalent to
+/// `RecursiveArrayConcatenation::new(RecursiveArrayConcatenation::new(a, b), c)`.
+/// a single array is returned as is, and no arrays result in an empty recursive array.
+#[macro_export]
+macro_rules! recursive_array_concat {
+    [] => {
+        ::recursive_array::EmptyRecursiveArray
+    };
+    [$array: expr $(,)?] => {
+        $array
+    };
+    [$first_array: expr, $second_array: expr $(, $array: expr)* $(,)?] => {
+        ::recursive_array::recursive_array_concat![
+            ::recursive_array::RecursiveArrayConcatenation::new($first_array, $second_array)
+            $(, $array)*
+        ]
+    };
+}
+
+/// a macro for getting the type of a generic array with the given item type and size.
+#[macro_export]
+macro_rules! recursive_array_type_of_size {
+    ($item_type: ty, $size: expr) => {
+        ::recursive_array::RecursiveArrayArrayWrapper<{$size}, $item_type>
+    };
+}
+
+/// asserts that the recursive array types `A` and `B` have the same length.
+///
+/// when called inside of a const context, for example an inline `const { ... }` block, a length mismatch causes a compile
+/// error instead of a runtime panic. this relies on panicking in const contexts, which is available on stable rust, and
+/// doesn't require `generic_const_exprs`. note that when used in generic code, the check is only performed once the function
+/// is monomorphized with concrete types.
+///
+/// # Panics
+///
+/// this function panics if `A::LENGTH` is not equal to `B::LENGTH`.
+pub const fn assert_same_length<A: RecursiveArray<T>, B: RecursiveArray<T>, T>() {
+    if A::LENGTH != B::LENGTH {
+        panic!("the lengths of the recursive array types are not equal");
+    }
+}
+
+/// returns the length of the recursive array type `R`. this is a const function, so it can be used wherever a constant is
+/// expected, for example as the length of a regular array, like `[T; length::<T, R>()]`, as long as `R` is a concrete type.
+///
+/// # Example
+///
+/// ```
+/// use recursive_array::{
+///     length, RecursiveArray, RecursiveArrayArrayWrapper, RecursiveArrayConcatenation, RecursiveArrayMultiplier,
+///     RecursiveArraySingleItem,
+/// };
+///
+/// type Row = RecursiveArrayConcatenation<u8, RecursiveArraySingleItem<u8>, RecursiveArrayArrayWrapper<2, u8>>;
+/// type Matrix = RecursiveArrayMultiplier<4, u8, Row>;
+///
+/// const ROW_LENGTH: usize = length::<u8, Row>();
+/// let row: [u8; ROW_LENGTH] = [0; length::<u8, Row>()];
+/// let matrix = [0u8; length::<u8, Matrix>()];
+/// let flat: RecursiveArrayArrayWrapper<{ length::<u8, Matrix>() }, u8> = RecursiveArrayArrayWrapper::new(matrix);
+/// assert_eq!(row.len(), 3);
+/// assert_eq!(flat.len(), 12);
+/// ```
+pub const fn length<T, R: RecursiveArray<T>>() -> usize {
+    R::LENGTH
+}
+
+/// returns a recursive array wrapper of length `N` whose elements are all equal to the given value. this is a const
+/// function, so it can be used to build lookup tables in `const` and `static` items. `T` must be `Copy` so that the value
+/// can be repeated in a const context.
+pub const fn const_repeat<const N: usize, T: Copy>(value: T) -> RecursiveArrayArrayWrapper<N, T> {
+    RecursiveArrayArrayWrapper { array: [value; N] }
+}
+
+/// flattens the given recursive arrays into a single vector, which contains clones of the elements of all of the arrays,
+/// in order.
+#[cfg(feature = "alloc")]
+pub fn concat_all<T: Clone, R: RecursiveArray<T>>(arrays: &[R]) -> alloc::vec::Vec<T> {
+    let mut result = alloc::vec::Vec::with_capacity(arrays.len() * R::LENGTH);
+    for array in arrays {
+        result.extend_from_slice(array.as_slice());
+    }
+    result
+}
+
+/// A const reimplementation of the [`transmute`](core::mem::transmute) function,
+/// avoiding problems when the compiler can't prove equal sizes.
+///
+/// # Safety
+/// Treat this the same as [`transmute`](core::mem::transmute), or (preferably) don't use it at all.
+unsafe fn runtime_checked_transmute<A, B>(a: A) -> B {
+    if core::mem::size_of::<A>() != core::mem::size_of::<B>() {
+        panic!(
+            "tried to transmute a type of size {} to a type of size {}",
+            core::mem::size_of::<A>(),
+            core::mem::size_of::<B>()
+        );
+    }
+
+    #[repr(C)]
+    union Union<A, B> {
+        a: core::mem::ManuallyDrop<A>,
+        b: core::mem::ManuallyDrop<B>,
+    }
+
+    let a = core::mem::ManuallyDrop::new(a);
+    core::mem::ManuallyDrop::into_inner(Union { a }.b)
+}
+
+/// panics if the length of the output array of some operation (`N`) is not equal to the amount of elements that the operation
+/// produces.
+fn check_output_length<const N: usize>(expected: usize) {
+    if N != expected {
+        panic!(
+            "tried to store the {} elements produced by an operation in an array of length {}",
+            expected, N,
+        );
+    }
+}
+
+/// checks that the length of the given bytes is equal to the expected size.
+#[cfg(feature = "bytemuck")]
+fn check_bytes_length(bytes: &[u8], expected: usize) -> Result<(), CastError> {
+    if bytes.len() != expected {
+        return Err(CastError::LengthMismatch {
+            expected,
+            actual: bytes.len(),
+        });
+    }
+    Ok(())
+}
+
+/// returns [`NotEnoughData`](arbitrary::Error::NotEnoughData) if the given data is shorter than the minimum amount of bytes
+/// required to build a value of type `A`.
+#[cfg(feature = "arbitrary")]
+fn check_arbitrary_data_length<'a, A: arbitrary::Arbitrary<'a>>(
+    u: &arbitrary::Unstructured<'a>,
+) -> arbitrary::Result<()> {
+    let (min_length, _) = A::size_hint(0);
+    if u.len() < min_length {
+        return Err(arbitrary::Error::NotEnoughData);
+    }
+    Ok(())
+}
+
+/// panics if the given bounds don't form a valid clamping range.
+fn check_clamp_bounds<T: PartialOrd>(min: T, max: T) {
+    if !matches!(
+        min.partial_cmp(&max),
+        Some(core::cmp::Ordering::Less | core::cmp::Ordering::Equal)
+    ) {
+        panic!("tried to clamp elements into an invalid range");
+    }
+}
+
+/// clamps the given value into the range `[min, max]`, leaving values which can't be compared, such as NaN, unchanged.
+fn clamp_element<T: PartialOrd>(value: T, min: T, max: T) -> T {
+    if value < min {
+        min
+    } else if value > max {
+        max
+    } else {
+        value
+    }
+}
+
+/// panics if the lengths of two arrays which are combined element-wise are not equal.
+fn check_same_length(lhs: usize, rhs: usize) {
+    if lhs != rhs {
+        panic!(
+            "tried to combine a recursive array of length {} with an array of length {}",
+            lhs, rhs,
+        );
+    }
+}
+
+/// combines the elements of the given slices, which must be of equal lengths, into a new array using the given function.
+fn zip_elements<T: Copy, U: Copy, V, const N: usize>(
+    lhs: &[T],
+    rhs: &[U],
+    mut f: impl FnMut(T, U) -> V,
+) -> RecursiveArrayArrayWrapper<N, V> {
+    check_same_length(lhs.len(), rhs.len());
+    check_output_length::<N>(lhs.len());
+    RecursiveArrayArrayWrapper::new(core::array::from_fn(|i| f(lhs[i], rhs[i])))
+}
+
+/// returns the index of the first element of the given slice which can be compared to itself, which excludes values like
+/// NaN.
+fn first_comparable_index<T: PartialOrd>(slice: &[T]) -> Option<usize> {
+    slice
+        .iter()
+        .position(|element| element.partial_cmp(element).is_some())
+}
+
+/// panics if the given index is out of the bounds of a recursive array of the given length.
+fn check_index(index: usize, length: usize) {
+    if index >= length {
+        panic!(
+            "tried to access index {} of a recursive array of length {}",
+            index, length,
+        );
     }
 }
-unsafe impl<const N: usize, T, A: RecursiveArray<T>> RecursiveArray<T>
-    for RecursiveArrayMultiplier<N, T, A>
-{
-    const LENGTH: usize = A::LENGTH * N;
+
+/// returns the scale of fixed-point numbers with the given amount of fractional bits, which is `2^frac`.
+fn fixed_point_scale(frac: usize) -> f32 {
+    (0..frac).fold(1.0, |scale, _| scale * 2.0)
 }
 
-/// a macro for instantiating a recursive array with the given elements.
-#[macro_export]
-macro_rules! recursive_array {
-    [] => {
-        ::recursive_array::EmptyRecursiveArray
-    };
-    [$item: expr $(,)?] => {
-        ::recursive_array::RecursiveArraySingleItem::new($item)
-    };
-    [$first_item: expr, $($item: expr),+] => {
-        ::recursive_array::RecursiveArrayConcatenation::new(
-            ::recursive_array::RecursiveArraySingleItem::new($first_item),
-            ::recursive_array::recursive_array![$($item),+],
-        )
-    };
+/// returns the wrapping (modulo 256) sum of the given bytes.
+fn wrapping_byte_sum(bytes: &[u8]) -> u8 {
+    bytes.iter().fold(0, |sum, byte| sum.wrapping_add(*byte))
 }
 
-/// a macro for getting the type of a generic array with the given item type and size.
-#[macro_export]
-macro_rules! recursive_array_type_of_size {
-    ($item_type: ty, $size: expr) => {
-        ::recursive_array::RecursiveArrayArrayWrapper<{$size}, $item_type>
-    };
+/// panics if the given permutation is not a permutation of `0..length`.
+fn check_permutation(perm: &[usize], length: usize) {
+    if perm.len() != length {
+        panic!(
+            "tried to apply a permutation of length {} to a recursive array of length {}",
+            perm.len(),
+            length,
+        );
+    }
+    for (i, &index) in perm.iter().enumerate() {
+        if index >= length || perm[..i].contains(&index) {
+            panic!(
+                "index {} appears out of bounds or more than once in the permutation",
+                index
+            );
+        }
+    }
 }
 
-/// A const reimplementation of the [`transmute`](core::mem::transmute) function,
-/// avoiding problems when the compiler can't prove equal sizes.
-///
-/// # Safety
-/// Treat this the same as [`transmute`](core::mem::transmute), or (preferably) don't use it at all.
-unsafe fn runtime_checked_transmute<A, B>(a: A) -> B {
-    if core::mem::size_of::<A>() != core::mem::size_of::<B>() {
+/// panics if the sub-array of the given length starting at the given offset is out of the bounds of a recursive array of the
+/// given length.
+fn check_subarray_bounds(offset: usize, length: usize, array_length: usize) {
+    if offset
+        .checked_add(length)
+        .is_none_or(|end| end > array_length)
+    {
         panic!(
-            "tried to transmute a type of size {} to a type of size {}",
-            core::mem::size_of::<A>(),
-            core::mem::size_of::<B>()
+            "tried to access {} elements at offset {} of a recursive array of length {}",
+            length, offset, array_length,
         );
     }
+}
 
-    #[repr(C)]
-    union Union<A, B> {
-        a: core::mem::ManuallyDrop<A>,
-        b: core::mem::ManuallyDrop<B>,
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+
+    #[test]
+    fn swap_with_slice_swaps_all_elements_in_place() {
+        let mut array = recursive_array![1, 2, 3];
+        let mut other = [4, 5, 6];
+        let array_ptr = array.as_slice().as_ptr();
+        let other_ptr = other.as_ptr();
+
+        array.swap_with_slice(&mut other);
+
+        assert_eq!(array.as_slice(), &[4, 5, 6]);
+        assert_eq!(other, [1, 2, 3]);
+        assert_eq!(array.as_slice().as_ptr(), array_ptr);
+        assert_eq!(other.as_ptr(), other_ptr);
     }
 
-    let a = core::mem::ManuallyDrop::new(a);
-    core::mem::ManuallyDrop::into_inner(Union { a }.b)
+    #[test]
+    fn swap_with_swaps_all_elements_across_types() {
+        let mut array = recursive_array![1, 2, 3];
+        let mut other = RecursiveArrayArrayWrapper::new([4, 5, 6]);
+        let array_ptr = array.as_slice().as_ptr();
+        let other_ptr = other.as_slice().as_ptr();
+
+        array.swap_with(&mut other);
+
+        assert_eq!(array.as_slice(), &[4, 5, 6]);
+        assert_eq!(other.as_slice(), &[1, 2, 3]);
+        assert_eq!(array.as_slice().as_ptr(), array_ptr);
+        assert_eq!(other.as_slice().as_ptr(), other_ptr);
+    }
+
+    #[test]
+    #[should_panic]
+    fn swap_with_slice_panics_on_length_mismatch() {
+        let mut array = recursive_array![1, 2, 3];
+        array.swap_with_slice(&mut [4, 5]);
+    }
+
+    #[cfg(feature = "nalgebra")]
+    fn check_nalgebra_round_trip<const N: usize>(array: [i32; N]) {
+        let vector: nalgebra::SVector<i32, N> = RecursiveArrayArrayWrapper::new(array).into();
+        assert_eq!(vector.as_slice(), &array);
+        let wrapper: RecursiveArrayArrayWrapper<N, i32> = vector.into();
+        assert_eq!(wrapper.as_slice(), &array);
+    }
+
+    #[test]
+    #[cfg(feature = "nalgebra")]
+    fn nalgebra_round_trip() {
+        check_nalgebra_round_trip([1, 2]);
+        check_nalgebra_round_trip([1, 2, 3]);
+        check_nalgebra_round_trip([1, 2, 3, 4]);
+    }
+
+    fn hash_of(value: &impl core::hash::Hash) -> u64 {
+        use core::hash::{BuildHasher, BuildHasherDefault};
+        BuildHasherDefault::<std::hash::DefaultHasher>::default().hash_one(value)
+    }
+
+    #[test]
+    fn content_comparisons_across_wrapper_and_concatenation() {
+        let wrapper = RecursiveArrayArrayWrapper::new([1, 2, 3]);
+        let concatenation = recursive_array![1, 2, 3];
+        let greater = recursive_array![1, 2, 4];
+
+        assert!(wrapper == concatenation);
+        assert!(concatenation == wrapper);
+        assert_eq!(hash_of(&wrapper), hash_of(&concatenation));
+        assert_eq!(
+            wrapper.partial_cmp(&concatenation),
+            Some(core::cmp::Ordering::Equal)
+        );
+        assert!(wrapper < greater);
+        assert!(greater > wrapper);
+        assert!(concatenation != RecursiveArrayArrayWrapper::new([1, 2]));
+        assert!(concatenation > RecursiveArrayArrayWrapper::new([1, 2]));
+    }
+
+    #[test]
+    fn content_comparisons_with_empty_array() {
+        let empty_wrapper = RecursiveArrayArrayWrapper::<0, i32>::new([]);
+        assert!(empty_wrapper == EmptyRecursiveArray);
+        assert!(EmptyRecursiveArray == empty_wrapper);
+        assert_eq!(hash_of(&empty_wrapper), hash_of(&EmptyRecursiveArray));
+        assert_eq!(
+            hash_of(&RecursiveArrayArrayWrapper::<0, std::string::String>::new(
+                []
+            )),
+            hash_of(&EmptyRecursiveArray)
+        );
+        assert!(EmptyRecursiveArray != recursive_array![1]);
+        assert!(EmptyRecursiveArray < recursive_array![1]);
+        assert!(recursive_array![1] > EmptyRecursiveArray);
+    }
+
+    /// an element which counts the amount of times it was dropped, by its id.
+    #[derive(Debug)]
+    struct DropTracker<'a> {
+        id: usize,
+        drops: &'a [core::cell::Cell<usize>],
+    }
+    impl Clone for DropTracker<'_> {
+        fn clone(&self) -> Self {
+            // clones get the id after the last original element, so that their drops are counted separately.
+            Self {
+                id: self.drops.len() - 1,
+                drops: self.drops,
+            }
+        }
+    }
+    impl Drop for DropTracker<'_> {
+        fn drop(&mut self) {
+            self.drops[self.id].set(self.drops[self.id].get() + 1);
+        }
+    }
+
+    fn drop_trackers<const N: usize>(
+        drops: &[core::cell::Cell<usize>],
+    ) -> RecursiveArrayArrayWrapper<N, DropTracker<'_>> {
+        RecursiveArrayArrayWrapper::new(core::array::from_fn(|id| DropTracker { id, drops }))
+    }
+
+    fn drop_counts(drops: &[core::cell::Cell<usize>]) -> std::vec::Vec<usize> {
+        drops.iter().map(core::cell::Cell::get).collect()
+    }
+
+    #[test]
+    fn into_iter_drops_each_element_once_on_early_stop() {
+        let drops: [core::cell::Cell<usize>; 6] = Default::default();
+        let mut elements = drop_trackers::<5>(&drops).into_iter();
+        drop(elements.next());
+        drop(elements.next_back());
+        assert_eq!(drop_counts(&drops), [1, 0, 0, 0, 1, 0]);
+        assert_eq!(elements.as_slice().len(), 3);
+        drop(elements);
+        assert_eq!(drop_counts(&drops), [1, 1, 1, 1, 1, 0]);
+    }
+
+    #[test]
+    fn into_iter_rev_drops_each_element_once_on_early_stop() {
+        let drops: [core::cell::Cell<usize>; 6] = Default::default();
+        let ids: std::vec::Vec<usize> = drop_trackers::<5>(&drops)
+            .into_iter_rev()
+            .take(2)
+            .map(|element| element.id)
+            .collect();
+        assert_eq!(ids, [4, 3]);
+        assert_eq!(drop_counts(&drops), [1, 1, 1, 1, 1, 0]);
+    }
+
+    #[test]
+    fn try_reduce_drops_each_element_once_on_error() {
+        let drops: [core::cell::Cell<usize>; 6] = Default::default();
+        let mut calls = 0;
+        let result = drop_trackers::<5>(&drops).try_reduce(|a, _| {
+            calls += 1;
+            if calls == 2 {
+                Err(())
+            } else {
+                Ok(a)
+            }
+        });
+        assert!(result.is_err());
+        assert_eq!(drop_counts(&drops), [1, 1, 1, 1, 1, 0]);
+    }
+
+    #[test]
+    fn zip_map_drops_each_element_once() {
+        let drops: [core::cell::Cell<usize>; 6] = Default::default();
+        let other_drops: [core::cell::Cell<usize>; 6] = Default::default();
+        let ids = drop_trackers::<5>(&drops)
+            .zip_map::<_, _, _, _, 5>(drop_trackers::<5>(&other_drops), |a, b| (a.id, b.id));
+        assert_eq!(ids.as_slice(), &[(0, 0), (1, 1), (2, 2), (3, 3), (4, 4)]);
+        assert_eq!(drop_counts(&drops), [1, 1, 1, 1, 1, 0]);
+        assert_eq!(drop_counts(&other_drops), [1, 1, 1, 1, 1, 0]);
+    }
+
+    #[test]
+    fn pad_to_drops_each_element_once() {
+        let drops: [core::cell::Cell<usize>; 4] = Default::default();
+        let fill = DropTracker {
+            id: 3,
+            drops: &drops,
+        };
+        let padded = drop_trackers::<3>(&drops).pad_to::<5>(fill);
+        assert_eq!(drop_counts(&drops), [0, 0, 0, 1]);
+        drop(padded);
+        assert_eq!(drop_counts(&drops), [1, 1, 1, 3]);
+    }
+
+    #[test]
+    fn uncons_drops_each_element_once() {
+        let drops: [core::cell::Cell<usize>; 4] = Default::default();
+        let (head, tail) = drop_trackers::<3>(&drops).uncons::<2>().unwrap();
+        assert_eq!(drop_counts(&drops), [0, 0, 0, 0]);
+        assert_eq!(head.id, 0);
+        drop(head);
+        drop(tail);
+        assert_eq!(drop_counts(&drops), [1, 1, 1, 0]);
+    }
+
+    #[test]
+    fn xor_of_equal_masks_is_all_zeros() {
+        let mask = RecursiveArrayArrayWrapper::new([0xdead_beef_u32, 0x1234_5678, u32::MAX]);
+        assert_eq!((mask.clone() ^ mask.clone()).as_slice(), &[0, 0, 0]);
+
+        let mut assigned = mask.clone();
+        assigned ^= mask;
+        assert_eq!(assigned.as_slice(), &[0, 0, 0]);
+    }
+
+    #[test]
+    fn uninit_array_built_element_by_element() {
+        let mut array =
+            RecursiveArrayArrayWrapper::<4, core::mem::MaybeUninit<std::string::String>>::uninit();
+        for (i, element) in array.as_mut_slice().iter_mut().enumerate() {
+            element.write(std::format!("element {}", i));
+        }
+        let array = unsafe { array.assume_init() };
+        assert_eq!(
+            array.as_slice(),
+            &["element 0", "element 1", "element 2", "element 3"]
+        );
+    }
+
+    #[test]
+    fn as_uninit_mut_overwrites_elements_in_place() {
+        let mut array = recursive_array![1, 2, 3];
+        unsafe {
+            for (i, element) in array.as_uninit_mut().iter_mut().enumerate() {
+                element.write(i * 10);
+            }
+        }
+        assert_eq!(array.as_slice(), &[0, 10, 20]);
+    }
+
+    #[test]
+    fn fold_rows_sums_matrix_rows() {
+        let matrix = RecursiveArrayMultiplier::new([
+            RecursiveArrayArrayWrapper::new([1, 2, 3]),
+            RecursiveArrayArrayWrapper::new([4, 5, 6]),
+        ]);
+        let column_sums = matrix.fold_rows([0; 3], |mut sums, row| {
+            for (sum, element) in sums.iter_mut().zip(row.as_slice()) {
+                *sum += element;
+            }
+            sums
+        });
+        assert_eq!(column_sums, [5, 7, 9]);
+
+        let total = matrix.fold_rows(0, |total, row| total + row.as_slice().iter().sum::<i32>());
+        assert_eq!(total, 21);
+    }
+
+    #[test]
+    fn argmax_and_argmin_skip_nan() {
+        let array = RecursiveArrayArrayWrapper::new([f32::NAN, 1.0, 3.0, f32::NAN, -2.0, 3.0]);
+        assert_eq!(array.argmax(), Some(2));
+        assert_eq!(array.argmin(), Some(4));
+
+        let leading_nan = RecursiveArrayArrayWrapper::new([f64::NAN, 1.0, 2.0]);
+        assert_eq!(leading_nan.argmax(), Some(2));
+        assert_eq!(leading_nan.argmin(), Some(1));
+
+        let all_nan = RecursiveArrayArrayWrapper::new([f32::NAN, f32::NAN]);
+        assert_eq!(all_nan.argmax(), None);
+        assert_eq!(RecursiveArrayArrayWrapper::<0, f32>::new([]).argmin(), None);
+    }
+
+    #[test]
+    fn concatenation_halves_peel_push_back_chain() {
+        let mut array = RecursiveArraySingleItem::new(1).push_back(2).push_back(3);
+
+        assert_eq!(array.left().as_slice(), &[1, 2]);
+        assert_eq!(array.right().get(), &3);
+        assert_eq!(array.left().left().get(), &1);
+        assert_eq!(array.left().right().get(), &2);
+
+        *array.left_mut().right_mut().get_mut() = 20;
+        *array.right_mut().get_mut() = 30;
+        assert_eq!(array.as_slice(), &[1, 20, 30]);
+
+        let (rest, last) = array.into_halves();
+        assert_eq!(last.into_inner(), 30);
+        let (first, second) = rest.into_halves();
+        assert_eq!(first.into_inner(), 1);
+        assert_eq!(second.into_inner(), 20);
+    }
+
+    #[test]
+    fn hamming_distance_of_known_pairs() {
+        let zeros = RecursiveArrayArrayWrapper::new([0u8; 4]);
+        assert_eq!(zeros.hamming_distance(&zeros), 0);
+        assert_eq!(
+            zeros.hamming_distance(&RecursiveArrayArrayWrapper::new([0xff; 4])),
+            32
+        );
+        assert_eq!(
+            recursive_array![0b1011_0000u8, 0x0f]
+                .hamming_distance(&recursive_array![0b0001_0000u8, 0x00]),
+            6
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn hamming_distance_panics_on_length_mismatch() {
+        recursive_array![0u8, 1].hamming_distance(&recursive_array![0u8]);
+    }
+
+    #[test]
+    fn checked_sum_at_u8_overflow_boundary() {
+        assert_eq!(recursive_array![200u8, 55].checked_sum(), Some(255));
+        assert_eq!(recursive_array![200u8, 55, 1].checked_sum(), None);
+        assert_eq!(recursive_array![255u8].checked_sum(), Some(255));
+        assert_eq!(
+            RecursiveArrayArrayWrapper::<0, u8>::new([]).checked_sum(),
+            Some(0)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    std::thread_local! {
+        static DESERIALIZED_DROPS: core::cell::Cell<usize> = const { core::cell::Cell::new(0) };
+    }
+
+    /// an element which counts the amount of times it was dropped on the current thread.
+    #[cfg(feature = "serde")]
+    #[derive(Debug, serde::Deserialize)]
+    struct DeserializedDropTracker(#[allow(dead_code)] u32);
+    #[cfg(feature = "serde")]
+    impl Drop for DeserializedDropTracker {
+        fn drop(&mut self) {
+            DESERIALIZED_DROPS.with(|drops| drops.set(drops.get() + 1));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_round_trip() {
+        let concatenation = recursive_array![1, 2, 3];
+        let json = serde_json::to_string(&concatenation).unwrap();
+        assert_eq!(json, "[1,2,3]");
+        let deserialized: RecursiveArrayConcatenation<
+            i32,
+            RecursiveArraySingleItem<i32>,
+            RecursiveArrayArrayWrapper<2, i32>,
+        > = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, concatenation);
+
+        let matrix = RecursiveArrayMultiplier::new([
+            RecursiveArrayArrayWrapper::new([1, 2]),
+            RecursiveArrayArrayWrapper::new([3, 4]),
+        ]);
+        let json = serde_json::to_string(&matrix).unwrap();
+        assert_eq!(json, "[1,2,3,4]");
+        let deserialized: RecursiveArrayMultiplier<2, i32, RecursiveArrayArrayWrapper<2, i32>> =
+            serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, matrix);
+
+        let json = serde_json::to_string(&EmptyRecursiveArray).unwrap();
+        let _: EmptyRecursiveArray = serde_json::from_str(&json).unwrap();
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_rejects_wrong_lengths() {
+        let error =
+            serde_json::from_str::<RecursiveArrayArrayWrapper<3, i32>>("[1,2]").unwrap_err();
+        assert!(
+            std::string::ToString::to_string(&error).contains("a recursive array of length 3"),
+            "{}",
+            error
+        );
+
+        let error =
+            serde_json::from_str::<RecursiveArrayArrayWrapper<3, i32>>("[1,2,3,4]").unwrap_err();
+        assert!(
+            std::string::ToString::to_string(&error).contains("a recursive array of length 3"),
+            "{}",
+            error
+        );
+
+        let error = serde_json::from_str::<
+            RecursiveArrayConcatenation<
+                i32,
+                RecursiveArrayConcatenation<
+                    i32,
+                    RecursiveArraySingleItem<i32>,
+                    RecursiveArrayArrayWrapper<2, i32>,
+                >,
+                RecursiveArrayArrayWrapper<2, i32>,
+            >,
+        >("[1,2,3,4,5,6]")
+        .unwrap_err();
+        assert!(
+            std::string::ToString::to_string(&error).contains("a recursive array of length 5"),
+            "{}",
+            error
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_drops_partially_deserialized_elements() {
+        DESERIALIZED_DROPS.with(|drops| drops.set(0));
+        let result = serde_json::from_str::<RecursiveArrayArrayWrapper<4, DeserializedDropTracker>>(
+            "[1,2,\"not a number\",4]",
+        );
+        assert!(result.is_err());
+        assert_eq!(DESERIALIZED_DROPS.with(core::cell::Cell::get), 2);
+
+        DESERIALIZED_DROPS.with(|drops| drops.set(0));
+        let result =
+            serde_json::from_str::<RecursiveArrayArrayWrapper<4, DeserializedDropTracker>>("[1,2]");
+        assert!(result.is_err());
+        assert_eq!(DESERIALIZED_DROPS.with(core::cell::Cell::get), 2);
+
+        DESERIALIZED_DROPS.with(|drops| drops.set(0));
+        let result = serde_json::from_str::<RecursiveArrayArrayWrapper<4, DeserializedDropTracker>>(
+            "[1,2,3,4,5]",
+        );
+        assert!(result.is_err());
+        assert_eq!(DESERIALIZED_DROPS.with(core::cell::Cell::get), 4);
+    }
+
+    #[test]
+    fn from_diagonal_fills_off_diagonal_with_default() {
+        let matrix =
+            RecursiveArrayMultiplier::from_diagonal(RecursiveArrayArrayWrapper::new([1, 2, 3]));
+        for (row, elements) in matrix.iter_sub().enumerate() {
+            for (column, element) in elements.as_slice().iter().enumerate() {
+                if row == column {
+                    assert_eq!(*element, row as i32 + 1);
+                } else {
+                    assert_eq!(*element, i32::default());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn count_ones_total_of_known_bit_pattern() {
+        assert_eq!(
+            recursive_array![0b1010_1010u8, 0xff, 0x00, 0x01].count_ones_total(),
+            13
+        );
+        assert_eq!(
+            RecursiveArrayArrayWrapper::new([u64::MAX; 4]).count_ones_total(),
+            256
+        );
+        assert_eq!(recursive_array![-1i16, 0x0f0f].count_ones_total(), 24);
+    }
+
+    #[test]
+    fn outer_product_of_known_vectors() {
+        let matrix = RecursiveArrayArrayWrapper::new([1, 2])
+            .outer(&RecursiveArrayArrayWrapper::new([3, 4, 5]));
+        assert_eq!(matrix.as_slice(), &[3, 4, 5, 6, 8, 10]);
+        assert_eq!(matrix.iter_sub().nth(1).unwrap().as_slice(), &[6, 8, 10]);
+    }
+
+    #[test]
+    fn convolve_matches_hand_computed_result() {
+        let array = RecursiveArrayArrayWrapper::new([1, 2, 3]);
+        let result = array.convolve::<4, 6>(&RecursiveArrayArrayWrapper::new([0, 1, 5, 2]));
+        assert_eq!(result.as_slice(), &[0, 1, 7, 15, 19, 6]);
+
+        // a kernel which is longer than the array.
+        let result = RecursiveArrayArrayWrapper::new([1, 2])
+            .convolve::<4, 5>(&RecursiveArrayArrayWrapper::new([1, 1, 1, 1]));
+        assert_eq!(result.as_slice(), &[1, 3, 3, 3, 2]);
+
+        let empty = RecursiveArrayArrayWrapper::<0, i32>::new([]);
+        assert!(empty
+            .convolve::<2, 0>(&RecursiveArrayArrayWrapper::new([1, 2]))
+            .is_empty());
+        assert!(array
+            .convolve::<0, 0>(&RecursiveArrayArrayWrapper::new([]))
+            .is_empty());
+    }
+
+    #[test]
+    fn cmp_iter_handles_prefixes_and_length_differences() {
+        use core::cmp::Ordering;
+
+        let array = recursive_array![1, 2, 3];
+        assert_eq!(array.cmp_iter([1, 2, 3]), Ordering::Equal);
+        // the iterator is a prefix of the array.
+        assert_eq!(array.cmp_iter([1, 2]), Ordering::Greater);
+        assert_eq!(array.cmp_iter(core::iter::empty()), Ordering::Greater);
+        // the array is a prefix of the iterator.
+        assert_eq!(array.cmp_iter([1, 2, 3, 0]), Ordering::Less);
+        // a difference before the end of the shorter one decides the result, regardless of the lengths.
+        assert_eq!(array.cmp_iter([1, 3]), Ordering::Less);
+        assert_eq!(array.cmp_iter([1, 1, 9, 9]), Ordering::Greater);
+        assert_eq!(
+            RecursiveArrayArrayWrapper::<0, i32>::new([]).cmp_iter(core::iter::empty()),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn histogram_of_known_distribution() {
+        let histogram = recursive_array![0u8, 1, 1, 3, 200, 3, 3].histogram::<4>();
+        assert_eq!(histogram.as_slice(), &[1, 2, 0, 3]);
+
+        let histogram = RecursiveArrayArrayWrapper::new([7u8; 10]).histogram::<256>();
+        assert_eq!(histogram.as_slice()[7], 10);
+        assert_eq!(histogram.as_slice().iter().sum::<usize>(), 10);
+    }
+
+    #[cfg(feature = "libm")]
+    #[test]
+    fn entropy_of_known_buffers() {
+        // since 7 and 256 are coprime, every byte value appears exactly twice.
+        let uniform =
+            RecursiveArrayArrayWrapper::<512, u8>::new(core::array::from_fn(|i| (i * 7) as u8));
+        assert!((uniform.entropy() - 8.0).abs() < 1e-4);
+
+        assert!((recursive_array![0u8, 1].entropy() - 1.0).abs() < 1e-6);
+        assert_eq!(RecursiveArrayArrayWrapper::new([42u8; 16]).entropy(), 0.0);
+        assert_eq!(RecursiveArrayArrayWrapper::<0, u8>::new([]).entropy(), 0.0);
+    }
+
+    #[test]
+    fn rotate_left_const_wraps_amounts_larger_than_length() {
+        let array = RecursiveArrayArrayWrapper::new([1, 2, 3, 4]);
+        assert_eq!(
+            array.clone().rotate_left_const::<6, 4>().as_slice(),
+            &[3, 4, 1, 2]
+        );
+        assert_eq!(
+            array.clone().rotate_left_const::<4, 4>().as_slice(),
+            &[1, 2, 3, 4]
+        );
+        assert_eq!(array.rotate_left_const::<9, 4>().as_slice(), &[2, 3, 4, 1]);
+    }
+
+    #[test]
+    fn saturating_dot_at_saturation_boundary() {
+        // the sum reaches the bound exactly, and then saturates.
+        assert_eq!(
+            recursive_array![100i8, 27].saturating_dot(&recursive_array![1, 1]),
+            127
+        );
+        assert_eq!(
+            recursive_array![100i8, 28].saturating_dot(&recursive_array![1, 1]),
+            127
+        );
+        assert_eq!(
+            recursive_array![-100i8, -28].saturating_dot(&recursive_array![1, 1]),
+            -128
+        );
+        assert_eq!(
+            recursive_array![-100i8, -29].saturating_dot(&recursive_array![1, 1]),
+            -128
+        );
+        // a single product saturates.
+        assert_eq!(
+            recursive_array![-128i8].saturating_dot(&recursive_array![-1]),
+            127
+        );
+        assert_eq!(
+            recursive_array![200u8, 0].saturating_dot(&recursive_array![2, 5]),
+            255
+        );
+        // each product is saturated before it is added, so the result differs from the true value of 54.
+        assert_eq!(
+            recursive_array![127i8, 2].saturating_dot(&recursive_array![2, -100]),
+            -1
+        );
+    }
+
+    #[test]
+    fn apply_permutation_identity() {
+        let mut array = recursive_array![10, 20, 30, 40];
+        array.apply_permutation(&[0, 1, 2, 3]);
+        assert_eq!(array.as_slice(), &[10, 20, 30, 40]);
+    }
+
+    #[test]
+    fn apply_permutation_single_long_cycle() {
+        let mut array = recursive_array![10, 20, 30, 40, 50, 60];
+        array.apply_permutation(&[1, 2, 3, 4, 5, 0]);
+        assert_eq!(array.as_slice(), &[20, 30, 40, 50, 60, 10]);
+
+        let mut array = RecursiveArrayArrayWrapper::new([10, 20, 30, 40, 50]);
+        array.apply_permutation(&[2, 0, 4, 1, 3]);
+        assert_eq!(array.as_slice(), &[30, 10, 50, 20, 40]);
+    }
+
+    #[test]
+    fn apply_permutation_disjoint_cycles() {
+        // a cycle of length 2, a fixed point, and a cycle of length 3.
+        let mut array = recursive_array![10, 20, 30, 40, 50, 60];
+        array.apply_permutation(&[1, 0, 2, 5, 3, 4]);
+        assert_eq!(array.as_slice(), &[20, 10, 30, 60, 40, 50]);
+
+        // the cycles are interleaved.
+        let mut array = RecursiveArrayArrayWrapper::new([10, 20, 30, 40, 50]);
+        array.apply_permutation(&[2, 3, 4, 1, 0]);
+        assert_eq!(array.as_slice(), &[30, 40, 50, 20, 10]);
+    }
+
+    #[test]
+    fn apply_permutation_empty() {
+        let mut array = RecursiveArrayArrayWrapper::<0, i32>::new([]);
+        array.apply_permutation(&[]);
+        let mut empty = EmptyRecursiveArray;
+        RecursiveArray::<i32>::apply_permutation(&mut empty, &[]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "tried to apply a permutation of length 2 to a recursive array of length 3"
+    )]
+    fn apply_permutation_panics_on_wrong_length() {
+        recursive_array![1, 2, 3].apply_permutation(&[1, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 3 appears out of bounds or more than once in the permutation")]
+    fn apply_permutation_panics_on_out_of_range_index() {
+        recursive_array![1, 2, 3].apply_permutation(&[0, 3, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 1 appears out of bounds or more than once in the permutation")]
+    fn apply_permutation_panics_on_duplicate_index() {
+        recursive_array![1, 2, 3].apply_permutation(&[1, 0, 1]);
+    }
+
+    #[test]
+    #[cfg(feature = "bytemuck")]
+    fn from_bytes_round_trip() {
+        let original = RecursiveArrayArrayWrapper::new([1u32, 0xdead_beef, u32::MAX]);
+        let mut copy = original.clone();
+        let bytes: &mut [u8] = bytemuck::cast_slice_mut(copy.as_mut_slice());
+
+        assert_eq!(
+            RecursiveArrayArrayWrapper::<3, u32>::from_bytes(bytes),
+            Ok(&original)
+        );
+        assert_eq!(
+            RecursiveArrayArrayWrapper::<3, u32>::from_bytes_owned(bytes),
+            Ok(original.clone())
+        );
+
+        let casted = RecursiveArrayArrayWrapper::<3, u32>::from_bytes_mut(bytes).unwrap();
+        casted.as_mut_slice()[0] = 7;
+        assert_eq!(
+            bytemuck::cast_slice::<u8, u32>(bytes),
+            &[7, 0xdead_beef, u32::MAX]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "bytemuck")]
+    fn from_bytes_reports_length_and_alignment_errors_separately() {
+        // the buffer is aligned to 4 bytes, so the bytes starting at offset 1 are misaligned for `u32`.
+        let mut buffer = [0u32; 3];
+        let bytes: &mut [u8] = bytemuck::cast_slice_mut(&mut buffer);
+
+        let length_mismatch = CastError::LengthMismatch {
+            expected: 8,
+            actual: 12,
+        };
+        assert_eq!(
+            RecursiveArrayArrayWrapper::<2, u32>::from_bytes(bytes),
+            Err(length_mismatch)
+        );
+        assert_eq!(
+            RecursiveArrayArrayWrapper::<2, u32>::from_bytes_mut(bytes),
+            Err(length_mismatch)
+        );
+        assert_eq!(
+            RecursiveArrayArrayWrapper::<2, u32>::from_bytes_owned(bytes),
+            Err(length_mismatch)
+        );
+
+        let misaligned = CastError::Misaligned {
+            required_alignment: 4,
+        };
+        assert_eq!(
+            RecursiveArrayArrayWrapper::<2, u32>::from_bytes(&bytes[1..9]),
+            Err(misaligned)
+        );
+        assert_eq!(
+            RecursiveArrayArrayWrapper::<2, u32>::from_bytes_mut(&mut bytes[1..9]),
+            Err(misaligned)
+        );
+        // the length is checked before the alignment.
+        assert_eq!(
+            RecursiveArrayArrayWrapper::<2, u32>::from_bytes(&bytes[1..]),
+            Err(CastError::LengthMismatch {
+                expected: 8,
+                actual: 11,
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "bytemuck")]
+    fn from_bytes_owned_accepts_misaligned_bytes() {
+        let mut buffer = [0u32; 3];
+        let bytes: &mut [u8] = bytemuck::cast_slice_mut(&mut buffer);
+        bytes[1..9].copy_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8]);
+        let array = RecursiveArrayArrayWrapper::<2, u32>::from_bytes_owned(&bytes[1..9]).unwrap();
+        assert_eq!(
+            array.as_slice(),
+            &[
+                u32::from_ne_bytes([1, 2, 3, 4]),
+                u32::from_ne_bytes([5, 6, 7, 8])
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "bytemuck")]
+    fn from_bytes_of_empty_array() {
+        assert!(RecursiveArrayArrayWrapper::<0, u32>::from_bytes(&[])
+            .unwrap()
+            .is_empty());
+        assert!(
+            RecursiveArrayArrayWrapper::<0, u32>::from_bytes_mut(&mut [])
+                .unwrap()
+                .is_empty()
+        );
+        assert!(RecursiveArrayArrayWrapper::<0, u32>::from_bytes_owned(&[])
+            .unwrap()
+            .is_empty());
+        // an empty slice taken from any offset has no alignment requirement, since nothing is read through it.
+        let buffer = [0u8; 3];
+        assert!(RecursiveArrayArrayWrapper::<0, u32>::from_bytes(&buffer[1..1]).is_ok());
+        assert_eq!(
+            RecursiveArrayArrayWrapper::<0, u32>::from_bytes(&buffer),
+            Err(CastError::LengthMismatch {
+                expected: 0,
+                actual: 3,
+            })
+        );
+    }
+
+    #[test]
+    fn filler_tracks_cursor_across_chunks() {
+        let mut array = RecursiveArrayArrayWrapper::new([0u8; 5]);
+        let mut filler = RecursiveArrayFiller::new(&mut array);
+        assert_eq!(filler.remaining(), 5);
+        assert!(!filler.is_full());
+
+        assert_eq!(filler.feed(&[1, 2]), 2);
+        assert_eq!(filler.remaining(), 3);
+        assert_eq!(filler.feed(&[]), 0);
+        assert_eq!(filler.remaining(), 3);
+        // this chunk crosses the end of the array, so only the bytes which fit are consumed.
+        assert_eq!(filler.feed(&[3, 4, 5, 6, 7]), 3);
+        assert_eq!(filler.remaining(), 0);
+        assert!(filler.is_full());
+        assert_eq!(filler.feed(&[8, 9]), 0);
+        assert_eq!(filler.finish(), Ok(()));
+        assert_eq!(array.as_slice(), &[1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn filler_finish_reports_missing_bytes() {
+        let mut array = RecursiveArrayArrayWrapper::new([0u8; 4]);
+        let mut filler = RecursiveArrayFiller::new(&mut array);
+        assert_eq!(filler.feed(&[1]), 1);
+        assert_eq!(filler.feed(&[2]), 1);
+        assert_eq!(filler.finish(), Err(Incomplete { missing: 2 }));
+        assert_eq!(array.as_slice(), &[1, 2, 0, 0]);
+
+        let mut array = RecursiveArrayArrayWrapper::new([0u8; 4]);
+        assert_eq!(
+            RecursiveArrayFiller::new(&mut array).finish(),
+            Err(Incomplete { missing: 4 })
+        );
+
+        let mut empty = RecursiveArrayArrayWrapper::<0, u8>::new([]);
+        let mut filler = RecursiveArrayFiller::new(&mut empty);
+        assert!(filler.is_full());
+        assert_eq!(filler.feed(&[1]), 0);
+        assert_eq!(filler.finish(), Ok(()));
+    }
+
+    /// returns the `LENGTH` of the type of the given recursive array.
+    fn length_of<T, R: RecursiveArray<T>>(_array: &R) -> usize {
+        R::LENGTH
+    }
+
+    #[test]
+    fn recursive_array_macro_spreads() {
+        let a = recursive_array![1, 2];
+        let b = RecursiveArrayArrayWrapper::new([3, 4, 5]);
+
+        let start = recursive_array![..a.clone(), 3];
+        assert_eq!(start.as_slice(), &[1, 2, 3]);
+        assert_eq!(length_of(&start), 3);
+
+        let middle = recursive_array![0, ..a.clone(), 3];
+        assert_eq!(middle.as_slice(), &[0, 1, 2, 3]);
+        assert_eq!(length_of(&middle), 4);
+
+        let end = recursive_array![0, ..a.clone()];
+        assert_eq!(end.as_slice(), &[0, 1, 2]);
+        assert_eq!(length_of(&end), 3);
+
+        let consecutive = recursive_array![0, ..a.clone(), ..b.clone(), 6];
+        assert_eq!(consecutive.as_slice(), &[0, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(length_of(&consecutive), 7);
+
+        // a spread of a single array is that array itself.
+        let alone: RecursiveArrayArrayWrapper<3, i32> = recursive_array![..b.clone()];
+        assert_eq!(alone.as_slice(), &[3, 4, 5]);
+        assert_eq!(length_of(&alone), 3);
+    }
+
+    #[test]
+    fn recursive_array_macro_trailing_commas_and_negative_literals() {
+        let a = recursive_array![1, 2];
+
+        let items = recursive_array![1, 2,];
+        assert_eq!(items.as_slice(), &[1, 2]);
+        assert_eq!(length_of(&items), 2);
+
+        let single = recursive_array![1,];
+        assert_eq!(single.as_slice(), &[1]);
+        assert_eq!(length_of(&single), 1);
+
+        let spread_last = recursive_array![0, ..a.clone(),];
+        assert_eq!(spread_last.as_slice(), &[0, 1, 2]);
+        assert_eq!(length_of(&spread_last), 3);
+
+        let spread_alone = recursive_array![..a.clone(),];
+        assert_eq!(spread_alone.as_slice(), &[1, 2]);
+        assert_eq!(length_of(&spread_alone), 2);
+
+        let negative = recursive_array![-1, 2];
+        assert_eq!(negative.as_slice(), &[-1, 2]);
+        assert_eq!(length_of(&negative), 2);
+
+        let negative_around_spread = recursive_array![-1, ..a, -3 - 4];
+        assert_eq!(negative_around_spread.as_slice(), &[-1, 1, 2, -7]);
+        assert_eq!(length_of(&negative_around_spread), 4);
+
+        let empty: EmptyRecursiveArray = recursive_array![];
+        assert_eq!(length_of::<i32, _>(&empty), 0);
+    }
+
+    #[test]
+    fn recursive_array_concat_macro_nests_from_left_to_right() {
+        type A = RecursiveArrayArrayWrapper<2, i32>;
+        type B = RecursiveArraySingleItem<i32>;
+        type C = RecursiveArrayArrayWrapper<3, i32>;
+        let a: A = RecursiveArrayArrayWrapper::new([1, 2]);
+        let b: B = RecursiveArraySingleItem::new(3);
+        let c: C = RecursiveArrayArrayWrapper::new([4, 5, 6]);
+
+        let _: EmptyRecursiveArray = recursive_array_concat![];
+
+        let single: A = recursive_array_concat![a.clone()];
+        assert_eq!(single.as_slice(), &[1, 2]);
+
+        let concatenated: RecursiveArrayConcatenation<
+            i32,
+            RecursiveArrayConcatenation<i32, A, B>,
+            C,
+        > = recursive_array_concat![a, b, c,];
+        assert_eq!(concatenated.as_slice(), &[1, 2, 3, 4, 5, 6]);
+        assert_eq!(length_of(&concatenated), 6);
+    }
 }